@@ -56,6 +56,15 @@ pub enum PrivateScoreError {
     #[msg("Pool utilization too high")]
     UtilizationTooHigh = 6107,
 
+    #[msg("Borrowing is paused protocol-wide")]
+    ProtocolPaused = 6108,
+
+    #[msg("Oracle price is stale")]
+    StaleOraclePrice = 6109,
+
+    #[msg("Invalid price oracle")]
+    InvalidOracle = 6110,
+
     // ═══════════════════════════════════════════════════════════════════════
     // CREDIT ERRORS (6200-6299)
     // ═══════════════════════════════════════════════════════════════════════
@@ -115,6 +124,15 @@ pub enum PrivateScoreError {
     #[msg("Health factor too low")]
     HealthFactorTooLow = 6308,
 
+    #[msg("Borrow exceeds credit tier limit")]
+    BorrowLimitExceeded = 6309,
+
+    #[msg("Required collateral exceeds caller's maximum")]
+    SlippageExceeded = 6310,
+
+    #[msg("Borrowing is on cooldown after a liquidation")]
+    BorrowCooldownActive = 6311,
+
     // ═══════════════════════════════════════════════════════════════════════
     // ZK PROOF ERRORS (6400-6499)
     // ═══════════════════════════════════════════════════════════════════════
@@ -0,0 +1,75 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! ACCRUE INTEREST - Permissionless crank keeping loan interest fresh
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! The keeper is rewarded only if interest actually accrued and at least
+//! KEEPER_MIN_ACCRUAL_INTERVAL passed since the last accrual, so the reward
+//! cannot be farmed by calling repeatedly.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{LendingPool, Loan, LoanStatus};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct AccrueInterest<'info> {
+    pub keeper: Signer<'info>,
+
+    #[account(mut)]
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        mut,
+        constraint = loan.pool == pool.key() @ PrivateScoreError::InvalidAccountState,
+        constraint = loan.status == LoanStatus::Active @ PrivateScoreError::LoanNotActive
+    )]
+    pub loan: Account<'info, Loan>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == pool.vault @ PrivateScoreError::InvalidVault
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = keeper_token_account.mint == pool.loan_mint @ PrivateScoreError::InvalidTokenMint
+    )]
+    pub keeper_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<AccrueInterest>) -> Result<()> {
+    let clock = Clock::get()?;
+    let borrow_index = ctx.accounts.pool.accrue_borrow_index(clock.unix_timestamp);
+    let loan = &mut ctx.accounts.loan;
+
+    let elapsed = clock.unix_timestamp.saturating_sub(loan.last_accrual_at);
+    let interest = loan.accrue_interest(borrow_index, clock.unix_timestamp)?;
+    let rewardable = interest > 0 && elapsed >= LendingPool::KEEPER_MIN_ACCRUAL_INTERVAL;
+
+    msg!("Accrued {} interest on loan {} over {}s", interest, loan.key(), elapsed);
+
+    if rewardable {
+        let reward = ctx.accounts.pool.take_keeper_reward_up_to(interest);
+        if reward > 0 {
+            let pool = &ctx.accounts.pool;
+            let pool_id_bytes = pool.pool_id.to_le_bytes();
+            let seeds = &[b"pool".as_ref(), pool_id_bytes.as_ref(), &[pool.bump]];
+
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.keeper_token_account.to_account_info(),
+                authority: ctx.accounts.pool.to_account_info(),
+            };
+            token::transfer(
+                CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, &[seeds]),
+                reward,
+            )?;
+            msg!("Keeper reward: {}", reward);
+        }
+    }
+
+    Ok(())
+}
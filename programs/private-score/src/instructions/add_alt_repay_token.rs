@@ -0,0 +1,58 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! ADD ALT REPAY TOKEN - Accept another stablecoin for repayments via an adapter
+//! ═══════════════════════════════════════════════════════════════════════════
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::state::{AdminMultisig, AltRepayToken, LendingPool};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct AddAltRepayToken<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = pool.authority == authority.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        constraint = mint.key() != pool.loan_mint @ PrivateScoreError::InvalidTokenMint
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = AltRepayToken::LEN,
+        seeds = [AltRepayToken::SEED_PREFIX, pool.key().as_ref(), mint.key().as_ref()],
+        bump
+    )]
+    pub alt_repay_token: Account<'info, AltRepayToken>,
+
+    /// CHECK: Adapter converting `mint` into the loan mint; must be executable
+    #[account(
+        constraint = adapter_program.executable @ PrivateScoreError::InvalidAccountState
+    )]
+    pub adapter_program: UncheckedAccount<'info>,
+
+    /// Required when the pool has an admin multisig; approvers sign via remaining_accounts
+    pub admin_multisig: Option<Account<'info, AdminMultisig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AddAltRepayToken>) -> Result<()> {
+    ctx.accounts.pool.require_admin_approval(ctx.accounts.admin_multisig.as_ref(), ctx.remaining_accounts)?;
+
+    let alt = &mut ctx.accounts.alt_repay_token;
+    alt.pool = ctx.accounts.pool.key();
+    alt.mint = ctx.accounts.mint.key();
+    alt.adapter_program = ctx.accounts.adapter_program.key();
+    alt.added_at = Clock::get()?.unix_timestamp;
+    alt.bump = ctx.bumps.alt_repay_token;
+
+    msg!("Pool {} accepts {} for repayment via {}", ctx.accounts.pool.pool_id, alt.mint, alt.adapter_program);
+    Ok(())
+}
@@ -0,0 +1,46 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! ADD COLLATERAL MINT - Accept another mint as collateral in a pool
+//! ═══════════════════════════════════════════════════════════════════════════
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::state::{AdminMultisig, CollateralWhitelist, LendingPool};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct AddCollateralMint<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = pool.authority == authority.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        mut,
+        seeds = [CollateralWhitelist::SEED_PREFIX, pool.key().as_ref()],
+        bump = collateral_whitelist.bump
+    )]
+    pub collateral_whitelist: Account<'info, CollateralWhitelist>,
+
+    /// Collateral is valued 1:1 per whole token, so decimals must match the pool's
+    #[account(
+        constraint = mint.decimals == pool.collateral_decimals @ PrivateScoreError::InvalidTokenMint,
+        constraint = mint.key() != pool.collateral_mint @ PrivateScoreError::AlreadyInitialized
+    )]
+    pub mint: Account<'info, Mint>,
+
+    /// Required when the pool has an admin multisig; approvers sign via remaining_accounts
+    pub admin_multisig: Option<Account<'info, AdminMultisig>>,
+}
+
+pub fn handler(ctx: Context<AddCollateralMint>) -> Result<()> {
+    ctx.accounts.pool.require_admin_approval(ctx.accounts.admin_multisig.as_ref(), ctx.remaining_accounts)?;
+
+    let whitelist = &mut ctx.accounts.collateral_whitelist;
+    whitelist.add(ctx.accounts.mint.key())?;
+    whitelist.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Collateral mint {} whitelisted for pool {}", ctx.accounts.mint.key(), ctx.accounts.pool.pool_id);
+    Ok(())
+}
@@ -0,0 +1,47 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! AUDIT COMMITMENT - Verify a revealed (score, salt) opens the commitment
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! Used when a score and salt are disclosed (e.g. to an auditor). Anyone who
+//! knows the opening can check it; a mismatch fails with InvalidCommitment.
+//!
+//! A valid opening is also mapped to its tier and compared with the stored
+//! tier. A mismatch means the record was registered or updated with the wrong
+//! tier; it emits TierMismatchDetected and the call still succeeds, so the
+//! flag is recorded rather than reverted.
+
+use anchor_lang::prelude::*;
+use crate::state::{CreditRecord, CreditTier, TierMismatchDetected};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct AuditCommitment<'info> {
+    pub auditor: Signer<'info>,
+
+    pub credit_record: Account<'info, CreditRecord>,
+}
+
+pub fn handler(ctx: Context<AuditCommitment>, score: u16, salt: [u8; 32]) -> Result<()> {
+    require!(score >= 300 && score <= 850, PrivateScoreError::InvalidCreditScore);
+
+    let credit_record = &ctx.accounts.credit_record;
+    credit_record.verify_opening(score, &salt)?;
+
+    msg!("Commitment for {} opened successfully", credit_record.owner);
+    msg!("Audited by {}", ctx.accounts.auditor.key());
+
+    let opened_tier = CreditTier::from_score(score);
+    if opened_tier != credit_record.tier {
+        emit!(TierMismatchDetected {
+            credit_record: credit_record.key(),
+            owner: credit_record.owner,
+            auditor: ctx.accounts.auditor.key(),
+            stored_tier: credit_record.tier,
+            opened_tier,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!("Stored tier {:?} does not match opened tier {:?}", credit_record.tier, opened_tier);
+    }
+
+    Ok(())
+}
@@ -4,7 +4,7 @@
 
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::{LendingPool, Loan, LoanType, LoanStatus};
+use crate::state::{Blocklist, CollateralWhitelist, CreditRecord, GlobalConfig, LendingPool, Loan, LoanType, LoanStatus, PriceFeed};
 use crate::errors::PrivateScoreError;
 
 #[derive(Accounts)]
@@ -12,12 +12,27 @@ pub struct BorrowStandard<'info> {
     #[account(mut)]
     pub borrower: Signer<'info>,
 
+    #[account(
+        seeds = [GlobalConfig::SEED],
+        bump = global_config.bump,
+        constraint = !global_config.global_paused @ PrivateScoreError::ProtocolPaused
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
     #[account(
         mut,
         constraint = pool.is_active @ PrivateScoreError::PoolInactive
     )]
     pub pool: Account<'info, LendingPool>,
 
+    /// Read for the pool's `min_tier_for_access` and the post-liquidation cooldown
+    #[account(
+        seeds = [b"credit", credit_record.owner.as_ref()],
+        bump = credit_record.bump,
+        constraint = credit_record.borrower_key() == borrower.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub credit_record: Account<'info, CreditRecord>,
+
     #[account(
         init,
         payer = borrower,
@@ -35,11 +50,32 @@ pub struct BorrowStandard<'info> {
 
     #[account(
         mut,
-        constraint = borrower_token_account.mint == pool.token_mint @ PrivateScoreError::InvalidTokenMint
+        constraint = borrower_token_account.mint == pool.loan_mint @ PrivateScoreError::InvalidTokenMint,
+        constraint = borrower_token_account.owner == borrower.key() @ PrivateScoreError::Unauthorized
     )]
     pub borrower_token_account: Account<'info, TokenAccount>,
 
-    #[account(mut)]
+    /// Extra collateral mints accepted by the pool, if it has a whitelist
+    #[account(
+        seeds = [CollateralWhitelist::SEED_PREFIX, pool.key().as_ref()],
+        bump = collateral_whitelist.bump
+    )]
+    pub collateral_whitelist: Option<Account<'info, CollateralWhitelist>>,
+
+    /// Required once the pool has a blocklist
+    #[account(
+        seeds = [Blocklist::SEED_PREFIX, pool.key().as_ref()],
+        bump = blocklist.bump
+    )]
+    pub blocklist: Option<Account<'info, Blocklist>>,
+
+    /// Required once the pool has a price oracle
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+
+    #[account(
+        mut,
+        constraint = pool.accepts_collateral(&collateral_account.mint, collateral_whitelist.as_deref()) @ PrivateScoreError::InvalidTokenMint
+    )]
     pub collateral_account: Account<'info, TokenAccount>,
 
     #[account(mut)]
@@ -49,16 +85,56 @@ pub struct BorrowStandard<'info> {
     pub token_program: Program<'info, Token>,
 }
 
-pub fn handler(ctx: Context<BorrowStandard>, amount: u64) -> Result<()> {
+pub fn handler(
+    ctx: Context<BorrowStandard>,
+    amount: u64,
+    max_collateral: u64,
+    duration: i64,
+    num_installments: u16,
+    installment_interval: i64,
+) -> Result<()> {
     let clock = Clock::get()?;
+    let borrow_index = ctx.accounts.pool.accrue_borrow_index(clock.unix_timestamp);
     let pool = &ctx.accounts.pool;
 
     require!(amount > 0, PrivateScoreError::InvalidAmount);
-    require!(pool.has_liquidity(amount), PrivateScoreError::InsufficientLiquidity);
+    require!(pool.within_borrow_cap(amount), PrivateScoreError::BorrowLimitExceeded);
+    pool.require_not_blocked(ctx.accounts.blocklist.as_ref(), &ctx.accounts.borrower.key())?;
+    require!(pool.tier_has_access(ctx.accounts.credit_record.tier), PrivateScoreError::TierDoesNotQualify);
+    require!(
+        !ctx.accounts.credit_record.in_liquidation_cooldown(clock.unix_timestamp),
+        PrivateScoreError::BorrowCooldownActive
+    );
+    let duration = pool.effective_loan_duration(duration)?;
+    require!(num_installments == 0 || installment_interval > 0, PrivateScoreError::InvalidLoanDuration);
+    require!(
+        installment_interval.saturating_mul(num_installments as i64) <= duration,
+        PrivateScoreError::InvalidLoanDuration
+    );
+    require!(pool.has_liquidity(amount, ctx.accounts.vault.amount), PrivateScoreError::InsufficientLiquidity);
 
     // Standard collateral ratio (150%)
-    let collateral_ratio = pool.base_collateral_ratio;
-    let required_collateral = (amount as u128 * collateral_ratio as u128 / 10000) as u64;
+    let collateral_ratio = pool.get_collateral_ratio(false);
+    let required_collateral = pool.get_required_collateral(amount, false);
+
+    // New positions need a buffer above the liquidation threshold
+    require!(
+        pool.opening_health_factor(required_collateral, amount) >= pool.min_opening_health_factor as u64,
+        PrivateScoreError::HealthFactorTooLow
+    );
+
+    // Guard against pool parameters changing after the user signed
+    require!(required_collateral <= max_collateral, PrivateScoreError::SlippageExceeded);
+
+    pool.require_collateral_value(
+        &ctx.accounts.collateral_account.mint,
+        required_collateral,
+        amount,
+        collateral_ratio,
+        ctx.accounts.price_feed.as_ref(),
+        clock.unix_timestamp,
+        ctx.accounts.global_config.max_oracle_staleness,
+    )?;
 
     require!(
         ctx.accounts.collateral_account.amount >= required_collateral,
@@ -66,6 +142,7 @@ pub fn handler(ctx: Context<BorrowStandard>, amount: u64) -> Result<()> {
     );
 
     // Transfer collateral
+    let vault_before = ctx.accounts.collateral_vault.amount;
     let cpi_accounts = Transfer {
         from: ctx.accounts.collateral_account.to_account_info(),
         to: ctx.accounts.collateral_vault.to_account_info(),
@@ -76,6 +153,13 @@ pub fn handler(ctx: Context<BorrowStandard>, amount: u64) -> Result<()> {
         required_collateral,
     )?;
 
+    // Fee-on-transfer mints deliver less than was sent; the loan must not open short
+    ctx.accounts.collateral_vault.reload()?;
+    require!(
+        ctx.accounts.collateral_vault.amount.saturating_sub(vault_before) >= required_collateral,
+        PrivateScoreError::InsufficientCollateral
+    );
+
     // Transfer borrowed funds
     let pool_id_bytes = pool.pool_id.to_le_bytes();
     let seeds = &[b"pool".as_ref(), pool_id_bytes.as_ref(), &[pool.bump]];
@@ -96,18 +180,25 @@ pub fn handler(ctx: Context<BorrowStandard>, amount: u64) -> Result<()> {
     loan.pool = ctx.accounts.pool.key();
     loan.principal = amount;
     loan.collateral_locked = required_collateral;
+    loan.collateral_mint = ctx.accounts.collateral_account.mint;
     loan.collateral_ratio = collateral_ratio;
-    loan.interest_rate = pool.interest_rate;
+    loan.liquidation_threshold = pool.liquidation_threshold;
+    loan.interest_rate = pool.borrow_rate();
+    loan.max_interest_multiple = pool.max_interest_multiple;
     loan.loan_type = LoanType::Standard;
     loan.status = LoanStatus::Active;
     loan.created_at = clock.unix_timestamp;
     loan.last_accrual_at = clock.unix_timestamp;
+    loan.entry_index = borrow_index;
+    loan.interest_free_until = clock.unix_timestamp.saturating_add(pool.interest_free_period);
+    loan.duration = duration;
+    loan.due_date = clock.unix_timestamp.saturating_add(duration);
+    loan.set_installment_schedule(num_installments, installment_interval);
     loan.bump = ctx.bumps.loan;
 
     // Update pool
     let pool = &mut ctx.accounts.pool;
-    pool.total_borrowed = pool.total_borrowed.saturating_add(amount);
-    pool.active_loans = pool.active_loans.saturating_add(1);
+    pool.open_loan(amount, loan.interest_rate);
     pool.updated_at = clock.unix_timestamp;
 
     msg!("Standard loan created: {} tokens at {}% collateral", amount, collateral_ratio / 100);
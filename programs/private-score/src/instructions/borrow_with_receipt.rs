@@ -0,0 +1,242 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! BORROW WITH RECEIPT - Credit-verified borrow redeeming a ProofReceipt
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! Second half of the split flow started by `submit_proof`. The receipt is
+//! closed on use, so it can back exactly one loan before it expires.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{Blocklist, CollateralWhitelist, CreditRecord, GlobalConfig, LendingPool, Loan, LoanType, LoanStatus, PriceFeed, ProofReceipt};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct BorrowWithReceipt<'info> {
+    #[account(mut)]
+    pub borrower: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalConfig::SEED],
+        bump = global_config.bump,
+        constraint = !global_config.global_paused @ PrivateScoreError::ProtocolPaused
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        constraint = pool.is_active @ PrivateScoreError::PoolInactive,
+        constraint = pool.accepts_credit_loans @ PrivateScoreError::CreditLoansNotAccepted
+    )]
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        mut,
+        seeds = [b"credit", credit_record.owner.as_ref()],
+        bump = credit_record.bump,
+        constraint = credit_record.can_borrow_as(&borrower.key()) @ PrivateScoreError::Unauthorized,
+        constraint = credit_record.is_active @ PrivateScoreError::CreditRecordInactive,
+        constraint = !credit_record.frozen @ PrivateScoreError::CreditRecordInactive
+    )]
+    pub credit_record: Account<'info, CreditRecord>,
+
+    #[account(
+        mut,
+        close = borrower,
+        seeds = [ProofReceipt::SEED_PREFIX, credit_record.key().as_ref(), &proof_receipt.nonce.to_le_bytes()],
+        bump = proof_receipt.bump,
+        constraint = proof_receipt.credit_record == credit_record.key() @ PrivateScoreError::Unauthorized,
+        constraint = proof_receipt.pool == pool.key() @ PrivateScoreError::InvalidAccountState,
+        constraint = proof_receipt.commitment == credit_record.commitment @ PrivateScoreError::ProofCommitmentMismatch
+    )]
+    pub proof_receipt: Account<'info, ProofReceipt>,
+
+    #[account(
+        init,
+        payer = borrower,
+        space = Loan::LEN,
+        seeds = [b"loan", pool.key().as_ref(), credit_record.borrower_key().as_ref(), &pool.active_loans.to_le_bytes()],
+        bump
+    )]
+    pub loan: Account<'info, Loan>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == pool.vault @ PrivateScoreError::InvalidVault
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = borrower_token_account.mint == pool.loan_mint @ PrivateScoreError::InvalidTokenMint,
+        constraint = borrower_token_account.owner == credit_record.borrower_key() @ PrivateScoreError::Unauthorized
+    )]
+    pub borrower_token_account: Account<'info, TokenAccount>,
+
+    /// Extra collateral mints accepted by the pool, if it has a whitelist
+    #[account(
+        seeds = [CollateralWhitelist::SEED_PREFIX, pool.key().as_ref()],
+        bump = collateral_whitelist.bump
+    )]
+    pub collateral_whitelist: Option<Account<'info, CollateralWhitelist>>,
+
+    /// Required once the pool has a blocklist
+    #[account(
+        seeds = [Blocklist::SEED_PREFIX, pool.key().as_ref()],
+        bump = blocklist.bump
+    )]
+    pub blocklist: Option<Account<'info, Blocklist>>,
+
+    /// Required once the pool has a price oracle
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+
+    #[account(
+        mut,
+        constraint = collateral_account.owner == borrower.key() @ PrivateScoreError::InvalidCollateralAccount,
+        constraint = pool.accepts_collateral(&collateral_account.mint, collateral_whitelist.as_deref()) @ PrivateScoreError::InvalidTokenMint
+    )]
+    pub collateral_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"collateral_vault", loan.key().as_ref()],
+        bump
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<BorrowWithReceipt>,
+    amount: u64,
+    max_collateral: u64,
+    duration: i64,
+    num_installments: u16,
+    installment_interval: i64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let borrow_index = ctx.accounts.pool.accrue_borrow_index(clock.unix_timestamp);
+    let pool = &ctx.accounts.pool;
+    let credit_record = &ctx.accounts.credit_record;
+    let receipt = &ctx.accounts.proof_receipt;
+
+    require!(!receipt.is_expired(clock.unix_timestamp), PrivateScoreError::ProofExpired);
+
+    require!(amount > 0, PrivateScoreError::InvalidAmount);
+    require!(pool.within_borrow_cap(amount), PrivateScoreError::BorrowLimitExceeded);
+    pool.require_not_blocked(ctx.accounts.blocklist.as_ref(), &ctx.accounts.borrower.key())?;
+    pool.require_not_blocked(ctx.accounts.blocklist.as_ref(), &credit_record.borrower_key())?;
+    let duration = pool.effective_loan_duration(duration)?;
+    require!(num_installments == 0 || installment_interval > 0, PrivateScoreError::InvalidLoanDuration);
+    require!(
+        installment_interval.saturating_mul(num_installments as i64) <= duration,
+        PrivateScoreError::InvalidLoanDuration
+    );
+    require!(pool.has_liquidity(amount, ctx.accounts.vault.amount), PrivateScoreError::InsufficientLiquidity);
+    require!(credit_record.can_borrow(clock.unix_timestamp), PrivateScoreError::CreditExpired);
+    require!(!credit_record.in_liquidation_cooldown(clock.unix_timestamp), PrivateScoreError::BorrowCooldownActive);
+    require!(pool.tier_has_access(receipt.tier), PrivateScoreError::TierDoesNotQualify);
+
+    let collateral_ratio = pool.collateral_ratio_for_tier(receipt.tier);
+    let required_collateral = pool.required_collateral_at(amount, collateral_ratio);
+
+    // New positions need a buffer above the liquidation threshold
+    require!(
+        pool.opening_health_factor(required_collateral, amount) >= pool.min_opening_health_factor as u64,
+        PrivateScoreError::HealthFactorTooLow
+    );
+
+    // Guard against pool parameters changing after the user signed
+    require!(required_collateral <= max_collateral, PrivateScoreError::SlippageExceeded);
+
+    pool.require_collateral_value(
+        &ctx.accounts.collateral_account.mint,
+        required_collateral,
+        amount,
+        collateral_ratio,
+        ctx.accounts.price_feed.as_ref(),
+        clock.unix_timestamp,
+        ctx.accounts.global_config.max_oracle_staleness,
+    )?;
+
+    require!(
+        ctx.accounts.collateral_account.amount >= required_collateral,
+        PrivateScoreError::InsufficientCollateral
+    );
+    require!(
+        amount <= pool.max_borrow_for_tier(receipt.tier, required_collateral),
+        PrivateScoreError::BorrowLimitExceeded
+    );
+
+    // Transfer collateral
+    let vault_before = ctx.accounts.collateral_vault.amount;
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.collateral_account.to_account_info(),
+        to: ctx.accounts.collateral_vault.to_account_info(),
+        authority: ctx.accounts.borrower.to_account_info(),
+    };
+    token::transfer(
+        CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
+        required_collateral,
+    )?;
+
+    // Fee-on-transfer mints deliver less than was sent; the loan must not open short
+    ctx.accounts.collateral_vault.reload()?;
+    require!(
+        ctx.accounts.collateral_vault.amount.saturating_sub(vault_before) >= required_collateral,
+        PrivateScoreError::InsufficientCollateral
+    );
+
+    // Transfer borrowed funds
+    let pool_id_bytes = pool.pool_id.to_le_bytes();
+    let seeds = &[b"pool".as_ref(), pool_id_bytes.as_ref(), &[pool.bump]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.vault.to_account_info(),
+        to: ctx.accounts.borrower_token_account.to_account_info(),
+        authority: ctx.accounts.pool.to_account_info(),
+    };
+    token::transfer(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, &[seeds]),
+        amount,
+    )?;
+
+    // Create loan record
+    let loan = &mut ctx.accounts.loan;
+    loan.borrower = ctx.accounts.credit_record.borrower_key();
+    loan.pool = ctx.accounts.pool.key();
+    loan.principal = amount;
+    loan.collateral_locked = required_collateral;
+    loan.collateral_mint = ctx.accounts.collateral_account.mint;
+    loan.collateral_ratio = collateral_ratio;
+    loan.liquidation_threshold = pool.liquidation_threshold_for_tier(receipt.tier);
+    loan.interest_rate = pool.borrow_rate();
+    loan.max_interest_multiple = pool.max_interest_multiple;
+    loan.loan_type = LoanType::CreditVerified;
+    loan.status = LoanStatus::Active;
+    loan.proof_hash = receipt.proof_hash;
+    loan.credit_commitment = receipt.commitment;
+    loan.created_at = clock.unix_timestamp;
+    loan.last_accrual_at = clock.unix_timestamp;
+    loan.entry_index = borrow_index;
+    loan.interest_free_until = clock.unix_timestamp.saturating_add(pool.interest_free_period);
+    loan.duration = duration;
+    loan.due_date = clock.unix_timestamp.saturating_add(duration);
+    loan.set_installment_schedule(num_installments, installment_interval);
+    loan.bump = ctx.bumps.loan;
+
+    let standard_collateral = pool.get_required_collateral(amount, false);
+    let savings = loan.collateral_savings(standard_collateral);
+
+    // Update pool
+    let pool = &mut ctx.accounts.pool;
+    pool.open_loan(amount, loan.interest_rate);
+    pool.total_collateral_savings = pool.total_collateral_savings.saturating_add(savings);
+    pool.updated_at = clock.unix_timestamp;
+
+    ctx.accounts.credit_record.record_loan(amount);
+
+    msg!("Receipt-verified loan created: {} tokens at {}% collateral", amount, collateral_ratio / 100);
+    Ok(())
+}
@@ -0,0 +1,123 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! CANCEL LIQUIDATION AUCTION - Clear the auction flag on a recovered loan
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! If collateral value recovers after an auction starts, `liquidate` refuses
+//! the loan with LoanNotLiquidatable. This permissionless crank clears the
+//! stale flag so a later shortfall starts a fresh auction from the lowest
+//! bonus. The keeper is paid the pool's keeper reward for the first such
+//! check on a loan.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{GlobalConfig, LendingPool, Loan, LoanStatus, PriceFeed};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct CancelLiquidationAuction<'info> {
+    pub keeper: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalConfig::SEED],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        mut,
+        constraint = loan.pool == pool.key() @ PrivateScoreError::InvalidAccountState,
+        constraint = loan.status == LoanStatus::Active @ PrivateScoreError::LoanNotActive,
+        constraint = loan.is_in_auction() @ PrivateScoreError::InvalidAccountState
+    )]
+    pub loan: Account<'info, Loan>,
+
+    #[account(
+        seeds = [b"collateral_vault", loan.key().as_ref()],
+        bump
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+
+    /// Required for cosigned loans; counted with the borrower's collateral, as in liquidate
+    #[account(
+        seeds = [b"cosigner_vault", loan.key().as_ref()],
+        bump
+    )]
+    pub cosigner_vault: Option<Account<'info, TokenAccount>>,
+
+    /// Required once the pool has a price oracle, as in liquidate
+    pub price_oracle: Option<Account<'info, PriceFeed>>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == pool.vault @ PrivateScoreError::InvalidVault
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = keeper_token_account.mint == pool.loan_mint @ PrivateScoreError::InvalidTokenMint
+    )]
+    pub keeper_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<CancelLiquidationAuction>) -> Result<()> {
+    let clock = Clock::get()?;
+    let borrow_index = ctx.accounts.pool.accrue_borrow_index(clock.unix_timestamp);
+    let pool = &ctx.accounts.pool;
+    let loan = &mut ctx.accounts.loan;
+
+    loan.accrue_interest(borrow_index, clock.unix_timestamp)?;
+
+    // Same valuation start_liquidation_auction used to flag the loan
+    let cosigner_amount = if loan.has_cosigner() {
+        let cosigner_vault = ctx.accounts.cosigner_vault.as_ref().ok_or(PrivateScoreError::InvalidCollateralAccount)?;
+        cosigner_vault.amount
+    } else {
+        0
+    };
+    let collateral_value = pool.collateral_value(
+        ctx.accounts.collateral_vault.amount.saturating_add(cosigner_amount),
+        ctx.accounts.price_oracle.as_ref(),
+        clock.unix_timestamp,
+        ctx.accounts.global_config.max_oracle_staleness,
+    )?;
+    require!(
+        !loan.is_liquidatable(
+            collateral_value,
+            loan.effective_liquidation_threshold(pool.liquidation_threshold),
+            clock.unix_timestamp,
+        ),
+        PrivateScoreError::HealthFactorTooLow
+    );
+
+    loan.clear_auction();
+    msg!("Liquidation auction cancelled for recovered loan {}", loan.key());
+
+    // Paid once per loan, so flag/cancel cycles on a volatile loan cannot drain the insurance fund
+    let rewardable = !loan.auction_cancel_rewarded;
+    loan.auction_cancel_rewarded = true;
+    let reward = if rewardable { ctx.accounts.pool.take_keeper_reward() } else { 0 };
+    if reward > 0 {
+        let pool = &ctx.accounts.pool;
+        let pool_id_bytes = pool.pool_id.to_le_bytes();
+        let seeds = &[b"pool".as_ref(), pool_id_bytes.as_ref(), &[pool.bump]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.vault.to_account_info(),
+            to: ctx.accounts.keeper_token_account.to_account_info(),
+            authority: ctx.accounts.pool.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, &[seeds]),
+            reward,
+        )?;
+        msg!("Keeper reward: {}", reward);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! CANCEL RECOVERY - Abort a pending recovery during its timelock
+//! ═══════════════════════════════════════════════════════════════════════════
+
+use anchor_lang::prelude::*;
+use crate::state::{CreditRecord, RecoveryCancelled};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct CancelRecovery<'info> {
+    /// Either the record owner or its recovery authority
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"credit", credit_record.owner.as_ref()],
+        bump = credit_record.bump,
+        constraint = credit_record.has_pending_recovery() @ PrivateScoreError::InvalidAccountState,
+        constraint = credit_record.owner == signer.key()
+            || credit_record.recovery_authority == signer.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub credit_record: Account<'info, CreditRecord>,
+}
+
+pub fn handler(ctx: Context<CancelRecovery>) -> Result<()> {
+    let clock = Clock::get()?;
+    let credit_record = &mut ctx.accounts.credit_record;
+    credit_record.clear_recovery();
+
+    emit!(RecoveryCancelled {
+        credit_record: credit_record.key(),
+        cancelled_by: ctx.accounts.signer.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Recovery of credit record {} cancelled", credit_record.owner);
+    Ok(())
+}
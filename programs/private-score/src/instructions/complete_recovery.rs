@@ -0,0 +1,72 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! COMPLETE RECOVERY - Re-seed a credit record under its new owner
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! Copies the record to the new owner's PDA once the timelock has elapsed and
+//! closes the old one. Viewing keys point at the old record, so they are
+//! dropped and disclosure must be re-enabled by the new owner.
+
+use anchor_lang::prelude::*;
+use crate::state::{CreditRecord, CreditRecordRecovered};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct CompleteRecovery<'info> {
+    #[account(mut)]
+    pub recovery_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"credit", old_credit_record.owner.as_ref()],
+        bump = old_credit_record.bump,
+        constraint = old_credit_record.recovery_authority == recovery_authority.key() @ PrivateScoreError::Unauthorized,
+        constraint = old_credit_record.has_pending_recovery() @ PrivateScoreError::InvalidAccountState,
+        close = recovery_authority
+    )]
+    pub old_credit_record: Account<'info, CreditRecord>,
+
+    #[account(
+        init,
+        payer = recovery_authority,
+        space = CreditRecord::LEN,
+        seeds = [b"credit", old_credit_record.pending_owner.as_ref()],
+        bump
+    )]
+    pub new_credit_record: Account<'info, CreditRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CompleteRecovery>) -> Result<()> {
+    let clock = Clock::get()?;
+    let old = &ctx.accounts.old_credit_record;
+    require!(
+        clock.unix_timestamp >= old.recovery_executable_at(),
+        PrivateScoreError::InvalidAccountState
+    );
+
+    let old_owner = old.owner;
+    let new_owner = old.pending_owner;
+
+    let mut record = (**old).clone();
+    record.owner = new_owner;
+    record.borrow_delegate = Pubkey::default();
+    record.clear_recovery();
+    record.close_session();
+    record.active_viewing_keys = 0;
+    record.disclosure_enabled = false;
+    record.updated_at = clock.unix_timestamp;
+    record.bump = ctx.bumps.new_credit_record;
+    ctx.accounts.new_credit_record.set_inner(record);
+
+    emit!(CreditRecordRecovered {
+        old_record: ctx.accounts.old_credit_record.key(),
+        new_record: ctx.accounts.new_credit_record.key(),
+        old_owner,
+        new_owner,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Credit record recovered: {} -> {}", old_owner, new_owner);
+    Ok(())
+}
@@ -0,0 +1,53 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! CREATE ADMIN MULTISIG - Attach m-of-n approval to a pool's admin actions
+//! ═══════════════════════════════════════════════════════════════════════════
+
+use anchor_lang::prelude::*;
+use crate::state::{AdminMultisig, LendingPool};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct CreateAdminMultisig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ PrivateScoreError::Unauthorized,
+        constraint = !pool.has_admin_multisig() @ PrivateScoreError::AlreadyInitialized
+    )]
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = AdminMultisig::LEN,
+        seeds = [AdminMultisig::SEED_PREFIX, pool.key().as_ref()],
+        bump
+    )]
+    pub admin_multisig: Account<'info, AdminMultisig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateAdminMultisig>, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    require!(!signers.is_empty() && signers.len() <= AdminMultisig::MAX_SIGNERS, PrivateScoreError::InvalidAccountState);
+    require!(threshold >= 1 && threshold as usize <= signers.len(), PrivateScoreError::InvalidAccountState);
+    for (i, signer) in signers.iter().enumerate() {
+        require!(!signers[..i].contains(signer), PrivateScoreError::InvalidAccountState);
+    }
+
+    let multisig = &mut ctx.accounts.admin_multisig;
+    multisig.pool = ctx.accounts.pool.key();
+    multisig.signers[..signers.len()].copy_from_slice(&signers);
+    multisig.num_signers = signers.len() as u8;
+    multisig.threshold = threshold;
+    multisig.created_at = Clock::get()?.unix_timestamp;
+    multisig.bump = ctx.bumps.admin_multisig;
+
+    let pool = &mut ctx.accounts.pool;
+    pool.admin_multisig = multisig.key();
+
+    msg!("Pool {} admin now requires {}-of-{} approval", pool.pool_id, threshold, signers.len());
+    Ok(())
+}
@@ -0,0 +1,52 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! CREATE BLOCKLIST - Start an empty list of addresses barred from a pool
+//! ═══════════════════════════════════════════════════════════════════════════
+
+use anchor_lang::prelude::*;
+use crate::state::{AdminMultisig, Blocklist, LendingPool};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct CreateBlocklist<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ PrivateScoreError::Unauthorized,
+        constraint = !pool.has_blocklist() @ PrivateScoreError::AlreadyInitialized
+    )]
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Blocklist::LEN,
+        seeds = [Blocklist::SEED_PREFIX, pool.key().as_ref()],
+        bump
+    )]
+    pub blocklist: Account<'info, Blocklist>,
+
+    /// Required when the pool has an admin multisig; approvers sign via remaining_accounts
+    pub admin_multisig: Option<Account<'info, AdminMultisig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateBlocklist>) -> Result<()> {
+    ctx.accounts.pool.require_admin_approval(ctx.accounts.admin_multisig.as_ref(), ctx.remaining_accounts)?;
+
+    let clock = Clock::get()?;
+    let blocklist = &mut ctx.accounts.blocklist;
+    blocklist.pool = ctx.accounts.pool.key();
+    blocklist.num_addresses = 0;
+    blocklist.updated_at = clock.unix_timestamp;
+    blocklist.bump = ctx.bumps.blocklist;
+
+    let pool = &mut ctx.accounts.pool;
+    pool.blocklist = blocklist.key();
+    pool.updated_at = clock.unix_timestamp;
+
+    msg!("Blocklist created for pool {}", pool.pool_id);
+    Ok(())
+}
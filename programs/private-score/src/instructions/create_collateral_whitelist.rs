@@ -0,0 +1,45 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! CREATE COLLATERAL WHITELIST - Start an empty list of extra collateral mints
+//! ═══════════════════════════════════════════════════════════════════════════
+
+use anchor_lang::prelude::*;
+use crate::state::{AdminMultisig, CollateralWhitelist, LendingPool};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct CreateCollateralWhitelist<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = pool.authority == authority.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = CollateralWhitelist::LEN,
+        seeds = [CollateralWhitelist::SEED_PREFIX, pool.key().as_ref()],
+        bump
+    )]
+    pub collateral_whitelist: Account<'info, CollateralWhitelist>,
+
+    /// Required when the pool has an admin multisig; approvers sign via remaining_accounts
+    pub admin_multisig: Option<Account<'info, AdminMultisig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateCollateralWhitelist>) -> Result<()> {
+    ctx.accounts.pool.require_admin_approval(ctx.accounts.admin_multisig.as_ref(), ctx.remaining_accounts)?;
+
+    let whitelist = &mut ctx.accounts.collateral_whitelist;
+    whitelist.pool = ctx.accounts.pool.key();
+    whitelist.num_mints = 0;
+    whitelist.updated_at = Clock::get()?.unix_timestamp;
+    whitelist.bump = ctx.bumps.collateral_whitelist;
+
+    msg!("Collateral whitelist created for pool {}", ctx.accounts.pool.pool_id);
+    Ok(())
+}
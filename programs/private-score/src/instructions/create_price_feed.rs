@@ -0,0 +1,63 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! CREATE PRICE FEED - Create a pushed collateral price feed for a pool
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! Once attached, verify_and_borrow must pass the feed and checks the value of
+//! the posted collateral, not just its token count, against the ratio. The
+//! first feed of a pool is attached here; later ones are switched to with
+//! set_price_oracle.
+
+use anchor_lang::prelude::*;
+use crate::state::{AdminMultisig, LendingPool, PriceFeed};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+#[instruction(updater: Pubkey)]
+pub struct CreatePriceFeed<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = PriceFeed::LEN,
+        seeds = [PriceFeed::SEED_PREFIX, pool.key().as_ref(), updater.as_ref()],
+        bump
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+
+    /// Required when the pool has an admin multisig; approvers sign via remaining_accounts
+    pub admin_multisig: Option<Account<'info, AdminMultisig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreatePriceFeed>, updater: Pubkey, price: u64) -> Result<()> {
+    ctx.accounts.pool.require_admin_approval(ctx.accounts.admin_multisig.as_ref(), ctx.remaining_accounts)?;
+    require!(price > 0, PrivateScoreError::InvalidOracle);
+
+    let clock = Clock::get()?;
+    let price_feed = &mut ctx.accounts.price_feed;
+    price_feed.pool = ctx.accounts.pool.key();
+    price_feed.updater = updater;
+    price_feed.price = price;
+    price_feed.updated_at = clock.unix_timestamp;
+    price_feed.bump = ctx.bumps.price_feed;
+
+    let pool = &mut ctx.accounts.pool;
+    if !pool.has_price_oracle() {
+        pool.price_oracle = price_feed.key();
+        pool.updated_at = clock.unix_timestamp;
+        msg!("Price feed {} attached to pool {}", price_feed.key(), pool.pool_id);
+    } else {
+        msg!("Price feed {} created for pool {}", price_feed.key(), pool.pool_id);
+    }
+    msg!("Updater: {}, price: {}", updater, price);
+    Ok(())
+}
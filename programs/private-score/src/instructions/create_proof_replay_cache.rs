@@ -0,0 +1,55 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! CREATE PROOF REPLAY CACHE - Start tracking recently used proofs for a pool
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! Once attached, credit borrows, proof submissions and collateral releases
+//! must pass this account so byte-identical proofs are rejected.
+
+use anchor_lang::prelude::*;
+use crate::state::{AdminMultisig, LendingPool, ProofReplayCache};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct CreateProofReplayCache<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ PrivateScoreError::Unauthorized,
+        constraint = !pool.has_proof_replay_cache() @ PrivateScoreError::AlreadyInitialized
+    )]
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ProofReplayCache::LEN,
+        seeds = [ProofReplayCache::SEED_PREFIX, pool.key().as_ref()],
+        bump
+    )]
+    pub proof_replay_cache: Account<'info, ProofReplayCache>,
+
+    /// Required when the pool has an admin multisig; approvers sign via remaining_accounts
+    pub admin_multisig: Option<Account<'info, AdminMultisig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateProofReplayCache>) -> Result<()> {
+    ctx.accounts.pool.require_admin_approval(ctx.accounts.admin_multisig.as_ref(), ctx.remaining_accounts)?;
+
+    let clock = Clock::get()?;
+    let cache = &mut ctx.accounts.proof_replay_cache;
+    cache.pool = ctx.accounts.pool.key();
+    cache.next_index = 0;
+    cache.updated_at = clock.unix_timestamp;
+    cache.bump = ctx.bumps.proof_replay_cache;
+
+    let pool = &mut ctx.accounts.pool;
+    pool.proof_replay_cache = cache.key();
+    pool.updated_at = clock.unix_timestamp;
+
+    msg!("Proof replay cache created for pool {}", pool.pool_id);
+    Ok(())
+}
@@ -0,0 +1,52 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! CREATE TIER TABLE - Define custom credit bands for a pool
+//! ═══════════════════════════════════════════════════════════════════════════
+
+use anchor_lang::prelude::*;
+use crate::state::{AdminMultisig, LendingPool, TierBand, TierTable};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct CreateTierTable<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = TierTable::LEN,
+        seeds = [TierTable::SEED_PREFIX, pool.key().as_ref()],
+        bump
+    )]
+    pub tier_table: Account<'info, TierTable>,
+
+    /// Required when the pool has an admin multisig; approvers sign via remaining_accounts
+    pub admin_multisig: Option<Account<'info, AdminMultisig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateTierTable>, bands: Vec<TierBand>) -> Result<()> {
+    ctx.accounts.pool.require_admin_approval(ctx.accounts.admin_multisig.as_ref(), ctx.remaining_accounts)?;
+
+    let clock = Clock::get()?;
+    let table = &mut ctx.accounts.tier_table;
+    table.pool = ctx.accounts.pool.key();
+    table.set_bands(&bands)?;
+    table.updated_at = clock.unix_timestamp;
+    table.bump = ctx.bumps.tier_table;
+
+    // Borrows must pass the table from now on
+    let pool = &mut ctx.accounts.pool;
+    pool.tier_table = table.key();
+    pool.updated_at = clock.unix_timestamp;
+
+    msg!("Tier table created for pool {} with {} bands", ctx.accounts.pool.pool_id, table.num_bands);
+    Ok(())
+}
@@ -4,7 +4,7 @@
 
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::LendingPool;
+use crate::state::{Blocklist, LenderPosition, LendingPool};
 use crate::errors::PrivateScoreError;
 
 #[derive(Accounts)]
@@ -20,7 +20,14 @@ pub struct Deposit<'info> {
 
     #[account(
         mut,
-        constraint = lender_token_account.mint == pool.token_mint @ PrivateScoreError::InvalidTokenMint
+        seeds = [LenderPosition::SEED_PREFIX, pool.key().as_ref(), lender.key().as_ref()],
+        bump = lender_position.bump
+    )]
+    pub lender_position: Account<'info, LenderPosition>,
+
+    #[account(
+        mut,
+        constraint = lender_token_account.mint == pool.loan_mint @ PrivateScoreError::InvalidTokenMint
     )]
     pub lender_token_account: Account<'info, TokenAccount>,
 
@@ -30,11 +37,19 @@ pub struct Deposit<'info> {
     )]
     pub vault: Account<'info, TokenAccount>,
 
+    /// Required once the pool has a blocklist
+    #[account(
+        seeds = [Blocklist::SEED_PREFIX, pool.key().as_ref()],
+        bump = blocklist.bump
+    )]
+    pub blocklist: Option<Account<'info, Blocklist>>,
+
     pub token_program: Program<'info, Token>,
 }
 
 pub fn handler(ctx: Context<Deposit>, amount: u64) -> Result<()> {
     require!(amount > 0, PrivateScoreError::InvalidAmount);
+    ctx.accounts.pool.require_not_blocked(ctx.accounts.blocklist.as_ref(), &ctx.accounts.lender.key())?;
 
     let cpi_accounts = Transfer {
         from: ctx.accounts.lender_token_account.to_account_info(),
@@ -44,9 +59,13 @@ pub fn handler(ctx: Context<Deposit>, amount: u64) -> Result<()> {
     let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
     token::transfer(cpi_ctx, amount)?;
 
+    let clock = Clock::get()?;
     let pool = &mut ctx.accounts.pool;
-    pool.total_deposits = pool.total_deposits.saturating_add(amount);
-    pool.updated_at = Clock::get()?.unix_timestamp;
+    pool.accrue_borrow_index(clock.unix_timestamp);
+    let position = pool.position_for_deposit(amount);
+    pool.record_deposit(amount, position);
+    pool.updated_at = clock.unix_timestamp;
+    ctx.accounts.lender_position.record_deposit(position, clock.unix_timestamp);
 
     msg!("Deposited {} tokens into pool {}", amount, pool.pool_id);
     Ok(())
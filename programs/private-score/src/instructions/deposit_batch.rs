@@ -0,0 +1,77 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! DEPOSIT BATCH - Deposit into several pools in one transaction
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! `remaining_accounts` holds one group per amount, in order:
+//! [pool, vault, lender_token_account, lender_position, blocklist] (all but the
+//! blocklist writable). Every pool is checked individually exactly as in
+//! `deposit`. The blocklist slot is read only when the pool has one; otherwise
+//! pass any account, e.g. this program's ID.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{Blocklist, LenderPosition, LendingPool};
+use crate::errors::PrivateScoreError;
+
+const ACCOUNTS_PER_POOL: usize = 5;
+
+#[derive(Accounts)]
+pub struct DepositBatch<'info> {
+    #[account(mut)]
+    pub lender: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, DepositBatch<'info>>, amounts: Vec<u64>) -> Result<()> {
+    require!(!amounts.is_empty(), PrivateScoreError::InvalidAmount);
+    require!(
+        ctx.remaining_accounts.len() == amounts.len() * ACCOUNTS_PER_POOL,
+        PrivateScoreError::InvalidAccountState
+    );
+
+    let clock = Clock::get()?;
+    let lender = ctx.accounts.lender.key();
+
+    for (i, amount) in amounts.iter().copied().enumerate() {
+        let accounts = &ctx.remaining_accounts[i * ACCOUNTS_PER_POOL..(i + 1) * ACCOUNTS_PER_POOL];
+        let mut pool: Account<'info, LendingPool> = Account::try_from(&accounts[0])?;
+        let vault: Account<'info, TokenAccount> = Account::try_from(&accounts[1])?;
+        let lender_token_account: Account<'info, TokenAccount> = Account::try_from(&accounts[2])?;
+        let mut position: Account<'info, LenderPosition> = Account::try_from(&accounts[3])?;
+
+        require!(amount > 0, PrivateScoreError::InvalidAmount);
+        require!(pool.is_active, PrivateScoreError::PoolInactive);
+        if pool.has_blocklist() {
+            let blocklist: Account<'info, Blocklist> = Account::try_from(&accounts[4])?;
+            pool.require_not_blocked(Some(&blocklist), &lender)?;
+        }
+        require_keys_eq!(vault.key(), pool.vault, PrivateScoreError::InvalidVault);
+        require_keys_eq!(lender_token_account.mint, pool.loan_mint, PrivateScoreError::InvalidTokenMint);
+        require_keys_eq!(position.owner, lender, PrivateScoreError::Unauthorized);
+        require_keys_eq!(position.pool, pool.key(), PrivateScoreError::InvalidAccountState);
+
+        let cpi_accounts = Transfer {
+            from: lender_token_account.to_account_info(),
+            to: vault.to_account_info(),
+            authority: ctx.accounts.lender.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
+            amount,
+        )?;
+
+        pool.accrue_borrow_index(clock.unix_timestamp);
+        let units = pool.position_for_deposit(amount);
+        pool.record_deposit(amount, units);
+        pool.updated_at = clock.unix_timestamp;
+        position.record_deposit(units, clock.unix_timestamp);
+
+        pool.exit(ctx.program_id)?;
+        position.exit(ctx.program_id)?;
+
+        msg!("Deposited {} tokens into pool {}", amount, pool.pool_id);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! DISCLOSE CREDIT - Viewer reads a credit record through a viewing key
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! Emits a DisclosureResponse masked to the key's access level. Each call is
+//! recorded against the key (count, last access, minimum interval).
+//!
+//! A regulatory key granted for a pool with `require_proof_for_regulatory`
+//! (recorded on the key as `proof_pool`) must carry a proof against the
+//! record's commitment, verified as in `verify_and_borrow`, rather than
+//! relying on the stored tier alone. Other disclosures pass an empty proof.
+
+use anchor_lang::prelude::*;
+use crate::state::{AccessExpiringSoon, AccessLevel, CreditRecord, CreditTier, DisclosureResponse, LendingPool, ViewingKey};
+use crate::errors::PrivateScoreError;
+use super::verify_and_borrow::verify_credit_proof;
+
+#[derive(Accounts)]
+pub struct DiscloseCredit<'info> {
+    pub viewer: Signer<'info>,
+
+    #[account(
+        constraint = !credit_record.frozen @ PrivateScoreError::CreditRecordInactive,
+        constraint = credit_record.disclosures_allowed() @ PrivateScoreError::DisclosureNotEnabled
+    )]
+    pub credit_record: Account<'info, CreditRecord>,
+
+    #[account(
+        mut,
+        seeds = [b"viewing_key", credit_record.key().as_ref(), viewer.key().as_ref()],
+        bump = viewing_key.bump,
+        constraint = viewing_key.viewer == viewer.key() @ PrivateScoreError::Unauthorized,
+        constraint = viewing_key.credit_record == credit_record.key() @ PrivateScoreError::InvalidViewingKey
+    )]
+    pub viewing_key: Account<'info, ViewingKey>,
+
+    /// Required when the viewing key has a `proof_pool`
+    pub pool: Option<Account<'info, LendingPool>>,
+}
+
+pub fn handler(ctx: Context<DiscloseCredit>, proof: Vec<u8>, public_inputs: Vec<u8>) -> Result<()> {
+    let clock = Clock::get()?;
+    let credit_record = &ctx.accounts.credit_record;
+    let viewing_key = &mut ctx.accounts.viewing_key;
+
+    // Predicate-only keys may never see the stored record, see disclose_predicate
+    let access = viewing_key.access_level;
+    require!(!access.is_predicate_only(), PrivateScoreError::DisclosureDenied);
+
+    if access == AccessLevel::RegulatoryAccess && viewing_key.requires_proof() {
+        let pool = ctx.accounts.pool.as_ref().ok_or(PrivateScoreError::InvalidProof)?;
+        require_keys_eq!(pool.key(), viewing_key.proof_pool, PrivateScoreError::InvalidAccountState);
+        verify_credit_proof(pool, &credit_record.commitment, &proof, &public_inputs)?;
+    }
+
+    viewing_key.record_access(clock.unix_timestamp)?;
+
+    let show_history = access.can_view_history();
+
+    emit!(DisclosureResponse {
+        credit_record: credit_record.key(),
+        viewer: ctx.accounts.viewer.key(),
+        disclosed_at: clock.unix_timestamp,
+        tier: if access.can_view_tier() { credit_record.tier } else { CreditTier::Unknown },
+        loans_taken: if show_history { credit_record.loans_taken } else { 0 },
+        on_time_repayments: if show_history { credit_record.on_time_repayments } else { 0 },
+        late_repayments: if show_history { credit_record.late_repayments } else { 0 },
+        tier_disclosed: access.can_view_tier(),
+        history_disclosed: show_history,
+        full_access_granted: access.can_view_full(),
+    });
+
+    if viewing_key.is_expiring_soon(clock.unix_timestamp) {
+        emit!(AccessExpiringSoon {
+            credit_record: credit_record.key(),
+            owner: viewing_key.owner,
+            viewer: viewing_key.viewer,
+            expires_at: viewing_key.expires_at,
+            time_remaining: viewing_key.time_remaining(clock.unix_timestamp),
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    msg!("Credit record of {} disclosed to {}", credit_record.owner, ctx.accounts.viewer.key());
+    msg!("Access count: {}", viewing_key.access_count);
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! DISCLOSE PREDICATE - Viewer learns only whether the score meets a threshold
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! The viewer submits a proof (supplied by the owner) that the committed score
+//! is at least `required_score`. The proof is re-verified on-chain and only the
+//! pass/fail result is emitted; the tier and history stay hidden.
+
+use anchor_lang::prelude::*;
+use crate::state::{AccessExpiringSoon, CreditRecord, PredicateDisclosure, ViewingKey};
+use crate::errors::PrivateScoreError;
+use super::verify_and_borrow::verify_score_predicate;
+
+#[derive(Accounts)]
+pub struct DisclosePredicate<'info> {
+    pub viewer: Signer<'info>,
+
+    #[account(
+        constraint = !credit_record.frozen @ PrivateScoreError::CreditRecordInactive,
+        constraint = credit_record.disclosures_allowed() @ PrivateScoreError::DisclosureNotEnabled
+    )]
+    pub credit_record: Account<'info, CreditRecord>,
+
+    #[account(
+        mut,
+        seeds = [b"viewing_key", credit_record.key().as_ref(), viewer.key().as_ref()],
+        bump = viewing_key.bump,
+        constraint = viewing_key.viewer == viewer.key() @ PrivateScoreError::Unauthorized,
+        constraint = viewing_key.credit_record == credit_record.key() @ PrivateScoreError::InvalidViewingKey,
+        constraint = viewing_key.access_level.is_predicate_only() @ PrivateScoreError::InvalidAccessLevel
+    )]
+    pub viewing_key: Account<'info, ViewingKey>,
+}
+
+pub fn handler(
+    ctx: Context<DisclosePredicate>,
+    required_score: u16,
+    proof: Vec<u8>,
+    public_inputs: Vec<u8>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let credit_record = &ctx.accounts.credit_record;
+    let viewing_key = &mut ctx.accounts.viewing_key;
+
+    viewing_key.record_access(clock.unix_timestamp)?;
+
+    let meets_requirement = verify_score_predicate(&credit_record.commitment, required_score, &proof, &public_inputs)?;
+
+    emit!(PredicateDisclosure {
+        credit_record: credit_record.key(),
+        viewer: ctx.accounts.viewer.key(),
+        disclosed_at: clock.unix_timestamp,
+        required_score,
+        meets_requirement,
+    });
+
+    if viewing_key.is_expiring_soon(clock.unix_timestamp) {
+        emit!(AccessExpiringSoon {
+            credit_record: credit_record.key(),
+            owner: viewing_key.owner,
+            viewer: viewing_key.viewer,
+            expires_at: viewing_key.expires_at,
+            time_remaining: viewing_key.time_remaining(clock.unix_timestamp),
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    msg!("Predicate score >= {} for {}: {}", required_score, credit_record.owner, meets_requirement);
+    msg!("Access count: {}", viewing_key.access_count);
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! EMERGENCY WITHDRAW - Principal-only lender exit while emergency mode is on
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! Skips interest and index accounting entirely. The lender's whole deposit is
+//! closed out at its pro-rata share of the liquidity left in the vault, so
+//! exiting early locks in the current haircut; what remains unpaid stays with
+//! the lenders who have not exited.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{LenderPosition, LendingPool};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct EmergencyWithdraw<'info> {
+    #[account(mut)]
+    pub lender: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = pool.emergency_mode @ PrivateScoreError::InvalidAccountState
+    )]
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        mut,
+        seeds = [LenderPosition::SEED_PREFIX, pool.key().as_ref(), lender.key().as_ref()],
+        bump = lender_position.bump
+    )]
+    pub lender_position: Account<'info, LenderPosition>,
+
+    #[account(
+        mut,
+        constraint = lender_token_account.mint == pool.loan_mint @ PrivateScoreError::InvalidTokenMint
+    )]
+    pub lender_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == pool.vault @ PrivateScoreError::InvalidVault
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<EmergencyWithdraw>) -> Result<()> {
+    let clock = Clock::get()?;
+    let principal = ctx.accounts.lender_position.deposited_amount;
+    require!(principal > 0, PrivateScoreError::InvalidAmount);
+
+    let payout = ctx.accounts.pool.emergency_payout(principal, ctx.accounts.vault.amount);
+    let value = ctx.accounts.pool.redeem_value(principal);
+    ctx.accounts.lender_position.record_withdrawal(principal, clock.unix_timestamp)?;
+
+    let pool = &mut ctx.accounts.pool;
+    pool.record_redemption(principal, value);
+    pool.updated_at = clock.unix_timestamp;
+
+    if payout > 0 {
+        let pool = &ctx.accounts.pool;
+        let pool_id_bytes = pool.pool_id.to_le_bytes();
+        let seeds = &[b"pool".as_ref(), pool_id_bytes.as_ref(), &[pool.bump]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.vault.to_account_info(),
+            to: ctx.accounts.lender_token_account.to_account_info(),
+            authority: ctx.accounts.pool.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, &[seeds]),
+            payout,
+        )?;
+    }
+
+    msg!("Emergency withdrawal from pool {}: {} of {} principal", ctx.accounts.pool.pool_id, payout, principal);
+    Ok(())
+}
@@ -0,0 +1,47 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! EXPORT CREDIT HISTORY - Emit a portable summary of the owner's record
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! Other protocols and indexers can trust a CreditHistoryExported event by its
+//! emitting program id. The score itself is never included, only counters,
+//! tier and the commitment the counters belong to.
+
+use anchor_lang::prelude::*;
+use crate::state::{CreditHistoryExported, CreditRecord};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct ExportCreditHistory<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"credit", owner.key().as_ref()],
+        bump = credit_record.bump,
+        constraint = credit_record.owner == owner.key() @ PrivateScoreError::Unauthorized,
+        constraint = credit_record.is_active @ PrivateScoreError::CreditRecordInactive,
+        constraint = !credit_record.frozen @ PrivateScoreError::CreditRecordInactive
+    )]
+    pub credit_record: Account<'info, CreditRecord>,
+}
+
+pub fn handler(ctx: Context<ExportCreditHistory>) -> Result<()> {
+    let record = &ctx.accounts.credit_record;
+
+    emit!(CreditHistoryExported {
+        credit_record: record.key(),
+        owner: record.owner,
+        commitment: record.commitment,
+        tier: record.tier,
+        loans_taken: record.loans_taken,
+        on_time_repayments: record.on_time_repayments,
+        late_repayments: record.late_repayments,
+        repayment_ratio: record.repayment_ratio(),
+        total_borrowed: record.total_borrowed,
+        total_repaid: record.total_repaid,
+        nonce: record.nonce,
+        exported_at: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Credit history exported for {}", record.owner);
+    Ok(())
+}
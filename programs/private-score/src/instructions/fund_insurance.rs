@@ -0,0 +1,50 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! FUND INSURANCE - Top up the pool's insurance fund against bad debt
+//! ═══════════════════════════════════════════════════════════════════════════
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::LendingPool;
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct FundInsurance<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(mut)]
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        mut,
+        constraint = funder_token_account.mint == pool.loan_mint @ PrivateScoreError::InvalidTokenMint
+    )]
+    pub funder_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == pool.vault @ PrivateScoreError::InvalidVault
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<FundInsurance>, amount: u64) -> Result<()> {
+    require!(amount > 0, PrivateScoreError::InvalidAmount);
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.funder_token_account.to_account_info(),
+        to: ctx.accounts.vault.to_account_info(),
+        authority: ctx.accounts.funder.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+
+    let pool = &mut ctx.accounts.pool;
+    pool.insurance_fund = pool.insurance_fund.saturating_add(amount);
+    pool.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Insurance fund for pool {} topped up by {} (total {})", pool.pool_id, amount, pool.insurance_fund);
+    Ok(())
+}
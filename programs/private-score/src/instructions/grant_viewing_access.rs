@@ -3,7 +3,7 @@
 //! ═══════════════════════════════════════════════════════════════════════════
 
 use anchor_lang::prelude::*;
-use crate::state::{CreditRecord, ViewingKey, AccessLevel, ViewingKeyStatus};
+use crate::state::{CreditRecord, LendingPool, ViewingKey, AccessLevel, ViewingKeyStatus};
 use crate::errors::PrivateScoreError;
 
 #[derive(Accounts)]
@@ -16,7 +16,8 @@ pub struct GrantViewingAccess<'info> {
         mut,
         seeds = [b"credit", owner.key().as_ref()],
         bump = credit_record.bump,
-        constraint = credit_record.owner == owner.key() @ PrivateScoreError::Unauthorized
+        constraint = credit_record.owner == owner.key() @ PrivateScoreError::Unauthorized,
+        constraint = !credit_record.frozen @ PrivateScoreError::CreditRecordInactive
     )]
     pub credit_record: Account<'info, CreditRecord>,
 
@@ -29,6 +30,10 @@ pub struct GrantViewingAccess<'info> {
     )]
     pub viewing_key: Account<'info, ViewingKey>,
 
+    /// Pool a regulatory key is granted for; its `require_proof_for_regulatory`
+    /// is fixed on the key so the viewer cannot opt out at disclosure time
+    pub pool: Option<Account<'info, LendingPool>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -37,13 +42,23 @@ pub fn handler(
     viewer: Pubkey,
     access_level: u8,
     expiry: i64,
+    min_access_interval: i64,
+    active_from: i64,
 ) -> Result<()> {
     let clock = Clock::get()?;
-    let access = AccessLevel::from_u8(access_level);
 
-    require!(access != AccessLevel::None, PrivateScoreError::InvalidAccessLevel);
+    // Reject out-of-range levels explicitly rather than letting from_u8 map them to None
+    require!(access_level >= 1 && access_level <= 5, PrivateScoreError::InvalidAccessLevel);
+    let access = AccessLevel::from_u8(access_level);
+    // 0 asks for the default lifetime, so clients need not compute a timestamp
+    let expiry = if expiry == 0 { clock.unix_timestamp + ViewingKey::DEFAULT_EXPIRY } else { expiry };
     require!(expiry > clock.unix_timestamp, PrivateScoreError::InvalidExpiry);
     require!(expiry <= clock.unix_timestamp + ViewingKey::MAX_EXPIRY, PrivateScoreError::ExpiryTooLong);
+    // 0 makes the key usable immediately; a later time defers it, e.g. to an audit period
+    let active_from = if active_from == 0 { clock.unix_timestamp } else { active_from };
+    require!(active_from < expiry, PrivateScoreError::InvalidExpiry);
+    require!(min_access_interval >= 0, PrivateScoreError::InvalidDisclosureRequest);
+    require!(ctx.accounts.credit_record.can_grant_viewing_key(), PrivateScoreError::MaxAccessesReached);
 
     let viewing_key = &mut ctx.accounts.viewing_key;
     viewing_key.owner = ctx.accounts.owner.key();
@@ -52,20 +67,28 @@ pub fn handler(
     viewing_key.access_level = access;
     viewing_key.status = ViewingKeyStatus::Active;
     viewing_key.granted_at = clock.unix_timestamp;
+    viewing_key.active_from = active_from;
     viewing_key.expires_at = expiry;
+    viewing_key.expiry_warning_window = ViewingKey::DEFAULT_EXPIRY_WARNING_WINDOW;
     viewing_key.last_accessed_at = 0;
+    viewing_key.min_access_interval = min_access_interval;
     viewing_key.access_count = 0;
     viewing_key.max_accesses = 0; // Unlimited by default
     viewing_key.one_time_use = false;
     viewing_key.notify_on_access = true;
+    viewing_key.proof_pool = match &ctx.accounts.pool {
+        Some(pool) if access == AccessLevel::RegulatoryAccess && pool.require_proof_for_regulatory => pool.key(),
+        _ => Pubkey::default(),
+    };
     viewing_key.bump = ctx.bumps.viewing_key;
 
     // Enable disclosure on credit record
     let credit_record = &mut ctx.accounts.credit_record;
     credit_record.disclosure_enabled = true;
+    credit_record.active_viewing_keys = credit_record.active_viewing_keys.saturating_add(1);
 
     msg!("Viewing access granted to {} with level {:?}", viewer, access);
-    msg!("Expires at: {}", expiry);
+    msg!("Active from: {}, expires at: {}", active_from, expiry);
 
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,45 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! INITIALIZE GLOBAL CONFIG - One-time setup of program-wide parameters
+//! ═══════════════════════════════════════════════════════════════════════════
+
+use anchor_lang::prelude::*;
+use crate::state::GlobalConfig;
+
+#[derive(Accounts)]
+pub struct InitializeGlobalConfig<'info> {
+    #[account(mut)]
+    pub super_admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = super_admin,
+        space = GlobalConfig::LEN,
+        seeds = [GlobalConfig::SEED],
+        bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeGlobalConfig>,
+    compliance_authority: Pubkey,
+    fee_recipient: Pubkey,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let config = &mut ctx.accounts.global_config;
+
+    config.super_admin = ctx.accounts.super_admin.key();
+    config.compliance_authority = compliance_authority;
+    config.fee_recipient = fee_recipient;
+    config.max_oracle_staleness = GlobalConfig::DEFAULT_MAX_ORACLE_STALENESS;
+    config.created_at = clock.unix_timestamp;
+    config.updated_at = clock.unix_timestamp;
+    config.bump = ctx.bumps.global_config;
+
+    msg!("Global config initialized by {}", config.super_admin);
+    msg!("Compliance authority: {}", compliance_authority);
+
+    Ok(())
+}
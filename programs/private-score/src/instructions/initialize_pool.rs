@@ -22,12 +22,14 @@ pub struct InitializePool<'info> {
     )]
     pub pool: Account<'info, LendingPool>,
 
-    pub token_mint: Account<'info, Mint>,
+    pub loan_mint: Account<'info, Mint>,
+
+    pub collateral_mint: Account<'info, Mint>,
 
     #[account(
         init,
         payer = authority,
-        token::mint = token_mint,
+        token::mint = loan_mint,
         token::authority = pool,
         seeds = [b"vault", pool.key().as_ref()],
         bump
@@ -44,13 +46,23 @@ pub fn handler(
     pool_id: u64,
     base_collateral_ratio: u16,
     credit_collateral_ratio: u16,
+    liquidation_threshold: u16,
     interest_rate: u16,
+    min_rate_bps: u16,
+    max_rate_bps: u16,
     min_credit_score: u16,
+    verifier_program: Pubkey,
 ) -> Result<()> {
     require!(base_collateral_ratio >= 10000, PrivateScoreError::InvalidCollateralRatio);
     require!(credit_collateral_ratio >= 10000, PrivateScoreError::InvalidCollateralRatio);
     require!(credit_collateral_ratio <= base_collateral_ratio, PrivateScoreError::InvalidCollateralRatio);
-    require!(interest_rate <= 5000, PrivateScoreError::InvalidInterestRate);
+    // Positions need a buffer between origination and liquidation
+    require!(liquidation_threshold >= 10000, PrivateScoreError::InvalidCollateralRatio);
+    require!(liquidation_threshold < credit_collateral_ratio, PrivateScoreError::InvalidCollateralRatio);
+    require!(
+        min_rate_bps <= interest_rate && interest_rate <= max_rate_bps && max_rate_bps <= 5000,
+        PrivateScoreError::InvalidInterestRate
+    );
     require!(min_credit_score >= 300 && min_credit_score <= 850, PrivateScoreError::InvalidCreditScore);
 
     let pool = &mut ctx.accounts.pool;
@@ -58,20 +70,52 @@ pub fn handler(
 
     pool.authority = ctx.accounts.authority.key();
     pool.pool_id = pool_id;
-    pool.token_mint = ctx.accounts.token_mint.key();
+    pool.loan_mint = ctx.accounts.loan_mint.key();
+    pool.collateral_mint = ctx.accounts.collateral_mint.key();
+    pool.loan_decimals = ctx.accounts.loan_mint.decimals;
+    pool.collateral_decimals = ctx.accounts.collateral_mint.decimals;
     pool.vault = ctx.accounts.vault.key();
+    pool.verifier_program = verifier_program;
     pool.base_collateral_ratio = base_collateral_ratio;
     pool.credit_collateral_ratio = credit_collateral_ratio;
-    pool.liquidation_threshold = 11000; // 110%
+    pool.liquidation_threshold = liquidation_threshold;
+    // Strictly above the threshold, so tier-discounted ratios never open at it
+    pool.min_opening_health_factor = LendingPool::DEFAULT_MIN_OPENING_HEALTH_FACTOR
+        .clamp(liquidation_threshold + 1, credit_collateral_ratio);
     pool.interest_rate = interest_rate;
+    pool.min_rate_bps = min_rate_bps;
+    pool.max_rate_bps = max_rate_bps;
     pool.min_credit_score = min_credit_score;
+    pool.max_interest_multiple = 20000; // 2x principal
+    pool.circuit_version = 1;
+    pool.insurance_fee_bps = 1000; // 10% of interest
+    pool.reserve_factor_bps = LendingPool::DEFAULT_RESERVE_FACTOR_BPS;
+    pool.min_reserve_bps = LendingPool::DEFAULT_MIN_RESERVE_BPS;
+    pool.tier_max_borrow_bps = LendingPool::DEFAULT_TIER_MAX_BORROW_BPS;
+    pool.tier_liquidation_discount_bps = LendingPool::DEFAULT_TIER_LIQUIDATION_DISCOUNT_BPS;
+    pool.tier_collateral_discount_bps = LendingPool::DEFAULT_TIER_COLLATERAL_DISCOUNT_BPS;
+    pool.tier_on_time_rebate_bps = LendingPool::DEFAULT_TIER_ON_TIME_REBATE_BPS;
+    pool.interest_free_period = 0;
+    pool.max_loan_duration = LendingPool::DEFAULT_MAX_LOAN_DURATION;
+    pool.liquidation_cooldown = 0;
+    pool.keeper_reward = 0;
+    pool.dust_threshold = 0;
+    pool.max_borrow_per_tx = 0;
+    pool.max_proof_len = LendingPool::DEFAULT_MAX_PROOF_LEN;
+    pool.max_public_inputs_len = LendingPool::DEFAULT_MAX_PUBLIC_INPUTS_LEN;
     pool.total_deposits = 0;
+    pool.total_positions = 0;
     pool.total_borrowed = 0;
     pool.active_loans = 0;
+    pool.borrow_index = LendingPool::INDEX_SCALE;
+    pool.last_index_update = clock.unix_timestamp;
     pool.created_at = clock.unix_timestamp;
     pool.updated_at = clock.unix_timestamp;
     pool.is_active = true;
     pool.accepts_credit_loans = true;
+    pool.auction_liquidation = false;
+    pool.require_proof_for_regulatory = false;
+    pool.close_factor_bps = LendingPool::DEFAULT_CLOSE_FACTOR_BPS;
     pool.bump = ctx.bumps.pool;
 
     msg!("Pool {} initialized with {}% base / {}% credit collateral", 
@@ -0,0 +1,47 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! INITIATE RECOVERY - Start the timelock to move a record to a new owner
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! The current owner can cancel at any point during the timelock, so a
+//! compromised recovery authority cannot silently take over a live record.
+
+use anchor_lang::prelude::*;
+use crate::state::{CreditRecord, RecoveryInitiated};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct InitiateRecovery<'info> {
+    pub recovery_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"credit", credit_record.owner.as_ref()],
+        bump = credit_record.bump,
+        constraint = credit_record.recovery_authority != Pubkey::default() @ PrivateScoreError::Unauthorized,
+        constraint = credit_record.recovery_authority == recovery_authority.key() @ PrivateScoreError::Unauthorized,
+        constraint = !credit_record.has_pending_recovery() @ PrivateScoreError::InvalidAccountState
+    )]
+    pub credit_record: Account<'info, CreditRecord>,
+}
+
+pub fn handler(ctx: Context<InitiateRecovery>, new_owner: Pubkey) -> Result<()> {
+    let credit_record = &mut ctx.accounts.credit_record;
+    require!(new_owner != Pubkey::default(), PrivateScoreError::Unauthorized);
+    require!(new_owner != credit_record.owner, PrivateScoreError::InvalidAccountState);
+
+    let clock = Clock::get()?;
+    credit_record.pending_owner = new_owner;
+    credit_record.recovery_initiated_at = clock.unix_timestamp;
+
+    emit!(RecoveryInitiated {
+        credit_record: credit_record.key(),
+        recovery_authority: ctx.accounts.recovery_authority.key(),
+        current_owner: credit_record.owner,
+        pending_owner: new_owner,
+        executable_at: credit_record.recovery_executable_at(),
+    });
+
+    msg!("Recovery of {} to {} executable at {}",
+        credit_record.owner, new_owner, credit_record.recovery_executable_at());
+    Ok(())
+}
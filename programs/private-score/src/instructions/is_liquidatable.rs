@@ -0,0 +1,101 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! IS LIQUIDATABLE - Keeper view of whether `liquidate` would succeed
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! Read-only: interest is accrued on in-memory copies of the pool and loan,
+//! then the same eligibility and coverage rules as `liquidate` are applied.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::state::{GlobalConfig, LendingPool, Loan, LoanStatus, PriceFeed};
+use crate::errors::PrivateScoreError;
+use super::liquidate::LIQUIDATION_BONUS_BPS;
+
+#[derive(Accounts)]
+pub struct IsLiquidatable<'info> {
+    #[account(
+        seeds = [GlobalConfig::SEED],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        constraint = loan.pool == pool.key() @ PrivateScoreError::InvalidAccountState,
+        constraint = loan.status == LoanStatus::Active @ PrivateScoreError::LoanNotActive
+    )]
+    pub loan: Account<'info, Loan>,
+
+    #[account(
+        seeds = [b"collateral_vault", loan.key().as_ref()],
+        bump
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+
+    /// Required for cosigned loans
+    #[account(
+        seeds = [b"cosigner_vault", loan.key().as_ref()],
+        bump
+    )]
+    pub cosigner_vault: Option<Account<'info, TokenAccount>>,
+
+    /// Required once the pool has a price oracle, as in liquidate
+    pub price_oracle: Option<Account<'info, PriceFeed>>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LiquidationCheck {
+    pub liquidatable: bool,
+    pub total_debt: u64,
+    pub collateral_value: u64,
+    pub shortfall: u64,                  // Debt the collateral cannot cover after the bonus
+}
+
+pub fn handler(ctx: Context<IsLiquidatable>) -> Result<LiquidationCheck> {
+    let clock = Clock::get()?;
+    let mut pool = (*ctx.accounts.pool).clone();
+    let mut loan = (*ctx.accounts.loan).clone();
+
+    let borrow_index = pool.accrue_borrow_index(clock.unix_timestamp);
+    loan.accrue_interest(borrow_index, clock.unix_timestamp)?;
+
+    let cosigner_amount = if loan.has_cosigner() {
+        let cosigner_vault = ctx.accounts.cosigner_vault.as_ref().ok_or(PrivateScoreError::InvalidCollateralAccount)?;
+        cosigner_vault.amount
+    } else {
+        0
+    };
+    let collateral_amount = ctx.accounts.collateral_vault.amount.saturating_add(cosigner_amount);
+    let collateral_value = pool.collateral_value(
+        collateral_amount,
+        ctx.accounts.price_oracle.as_ref(),
+        clock.unix_timestamp,
+        ctx.accounts.global_config.max_oracle_staleness,
+    )?;
+    let total_debt = loan.total_debt();
+
+    let eligible = loan.is_liquidatable(
+        collateral_value,
+        loan.effective_liquidation_threshold(pool.liquidation_threshold),
+        clock.unix_timestamp,
+    );
+
+    // Auction pools only liquidate once an auction is running; zero collateral skips it
+    let (liquidatable, bonus_bps) = if collateral_amount == 0 {
+        (eligible, 0)
+    } else if pool.auction_liquidation {
+        (eligible && loan.is_in_auction(), loan.auction_bonus_bps(clock.unix_timestamp) as u64)
+    } else {
+        (eligible, LIQUIDATION_BONUS_BPS)
+    };
+
+    let coverable_debt = (collateral_value as u128 * 10000 / (10000 + bonus_bps as u128)) as u64;
+
+    Ok(LiquidationCheck {
+        liquidatable,
+        total_debt,
+        collateral_value,
+        shortfall: total_debt.saturating_sub(coverable_debt),
+    })
+}
@@ -4,29 +4,45 @@
 
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::{LendingPool, Loan, LoanStatus};
+use crate::state::{CreditRecord, GlobalConfig, LendingPool, Loan, LoanStatus, PriceFeed};
 use crate::errors::PrivateScoreError;
 
 /// Liquidation bonus for liquidators (5%)
-const LIQUIDATION_BONUS_BPS: u64 = 500;
+pub(crate) const LIQUIDATION_BONUS_BPS: u64 = 500;
 
 #[derive(Accounts)]
 pub struct Liquidate<'info> {
     #[account(mut)]
     pub liquidator: Signer<'info>,
 
+    #[account(
+        seeds = [GlobalConfig::SEED],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
     #[account(mut)]
     pub pool: Account<'info, LendingPool>,
 
     #[account(
         mut,
+        constraint = loan.pool == pool.key() @ PrivateScoreError::InvalidAccountState,
         constraint = loan.status == LoanStatus::Active @ PrivateScoreError::LoanNotActive
     )]
     pub loan: Account<'info, Loan>,
 
+    /// The borrower's record, to start its re-borrow cooldown. Liquidation never
+    /// depends on it: a record that no longer maps to the borrower is ignored.
+    #[account(
+        mut,
+        seeds = [b"credit", credit_record.owner.as_ref()],
+        bump = credit_record.bump
+    )]
+    pub credit_record: Option<Account<'info, CreditRecord>>,
+
     #[account(
         mut,
-        constraint = liquidator_token_account.mint == pool.token_mint @ PrivateScoreError::InvalidTokenMint
+        constraint = liquidator_token_account.mint == pool.loan_mint @ PrivateScoreError::InvalidTokenMint
     )]
     pub liquidator_token_account: Account<'info, TokenAccount>,
 
@@ -39,34 +55,120 @@ pub struct Liquidate<'info> {
     #[account(mut)]
     pub collateral_vault: Account<'info, TokenAccount>,
 
+    /// Required for cosigned loans; drawn down per the pool's seizure order
+    #[account(
+        mut,
+        seeds = [b"cosigner_vault", loan.key().as_ref()],
+        bump
+    )]
+    pub cosigner_vault: Option<Account<'info, TokenAccount>>,
+
     #[account(mut)]
     pub liquidator_collateral_account: Account<'info, TokenAccount>,
 
-    /// CHECK: Price oracle for collateral value (simplified)
-    pub price_oracle: AccountInfo<'info>,
+    /// Receives whatever borrower collateral is left once the loan is closed
+    #[account(
+        mut,
+        constraint = borrower_collateral_account.owner == loan.borrower @ PrivateScoreError::InvalidCollateralAccount,
+        constraint = borrower_collateral_account.mint == collateral_vault.mint @ PrivateScoreError::InvalidTokenMint
+    )]
+    pub borrower_collateral_account: Account<'info, TokenAccount>,
+
+    /// Required when a closed cosigned loan leaves part of the pledge unseized
+    #[account(
+        mut,
+        constraint = cosigner_collateral_account.owner == loan.cosigner @ PrivateScoreError::InvalidCollateralAccount
+    )]
+    pub cosigner_collateral_account: Option<Account<'info, TokenAccount>>,
+
+    /// Required once the pool has a price oracle; read at liquidation, so a
+    /// rotated oracle applies to loans opened before the switch
+    pub price_oracle: Option<Account<'info, PriceFeed>>,
 
     pub token_program: Program<'info, Token>,
 }
 
 pub fn handler(ctx: Context<Liquidate>) -> Result<()> {
     let clock = Clock::get()?;
+    let borrow_index = ctx.accounts.pool.accrue_borrow_index(clock.unix_timestamp);
     let loan = &mut ctx.accounts.loan;
     let pool = &ctx.accounts.pool;
 
     // Accrue interest first
-    loan.accrue_interest(clock.unix_timestamp);
+    loan.accrue_interest(borrow_index, clock.unix_timestamp)?;
 
-    // Get collateral value (simplified - would use oracle in production)
-    let collateral_value = ctx.accounts.collateral_vault.amount;
+    let cosigner_amount = if loan.has_cosigner() {
+        let cosigner_vault = ctx.accounts.cosigner_vault.as_ref().ok_or(PrivateScoreError::InvalidCollateralAccount)?;
+        cosigner_vault.amount
+    } else {
+        0
+    };
+    let borrower_amount = ctx.accounts.collateral_vault.amount;
+    let collateral_amount = borrower_amount.saturating_add(cosigner_amount);
+    let price_oracle = ctx.accounts.price_oracle.as_ref();
+    let collateral_value = pool.collateral_value(
+        collateral_amount,
+        price_oracle,
+        clock.unix_timestamp,
+        ctx.accounts.global_config.max_oracle_staleness,
+    )?;
     
-    // Check if loan is undercollateralized
+    // Check if loan is undercollateralized or past maturity
     require!(
-        loan.is_undercollateralized(collateral_value, pool.liquidation_threshold),
+        loan.is_liquidatable(
+            collateral_value,
+            loan.effective_liquidation_threshold(pool.liquidation_threshold),
+            clock.unix_timestamp,
+        ),
         PrivateScoreError::LoanNotLiquidatable
     );
 
+    if let Some(credit_record) = &mut ctx.accounts.credit_record {
+        if credit_record.borrower_key() == loan.borrower {
+            credit_record.start_liquidation_cooldown(pool.liquidation_cooldown, clock.unix_timestamp);
+        }
+    }
+
+    // Nothing to seize: the whole debt is written off and the loan closed
+    if collateral_amount == 0 {
+        let total_debt = loan.total_debt();
+        let principal_loss = loan.outstanding_principal();
+        loan.status = LoanStatus::Liquidated;
+        loan.closed_at = clock.unix_timestamp;
+
+        let pool = &mut ctx.accounts.pool;
+        pool.release_loan(loan.principal, loan.interest_rate)?;
+        let insurance_covered = pool.absorb_shortfall(total_debt, principal_loss);
+        pool.updated_at = clock.unix_timestamp;
+
+        msg!("Loan {} had no collateral: {} written off (insurance covered {})", ctx.accounts.loan.key(), total_debt, insurance_covered);
+        return Ok(());
+    }
+
+    // Auction pools only allow liquidation once an auction has been started
+    let bonus_bps = if pool.auction_liquidation {
+        require!(loan.is_in_auction(), PrivateScoreError::LoanNotLiquidatable);
+        loan.auction_bonus_bps(clock.unix_timestamp) as u64
+    } else {
+        LIQUIDATION_BONUS_BPS
+    };
+
     let total_debt = loan.total_debt();
 
+    // Liquidator only repays what the collateral (less bonus) covers; the rest is bad debt
+    let coverable_debt = (collateral_value as u128 * 10000 / (10000 + bonus_bps as u128)) as u64;
+    let full_repayment = total_debt.min(coverable_debt);
+    let shortfall = total_debt.saturating_sub(full_repayment);
+
+    // Solvent positions are closed at most close_factor_bps of the debt per call
+    let close_limit = pool.max_liquidation_repay(total_debt);
+    let is_partial = shortfall == 0 && close_limit < total_debt;
+    let debt_repaid = if is_partial { close_limit } else { full_repayment };
+    // Interest never entered total_deposits, so only lost principal is socialized to lenders.
+    // Repayment clears accrued interest before principal.
+    let principal_repaid = debt_repaid.saturating_sub(loan.outstanding_interest());
+    let principal_loss = loan.outstanding_principal().saturating_sub(principal_repaid);
+
     // Liquidator repays the debt
     let cpi_accounts = Transfer {
         from: ctx.accounts.liquidator_token_account.to_account_info(),
@@ -75,12 +177,21 @@ pub fn handler(ctx: Context<Liquidate>) -> Result<()> {
     };
     token::transfer(
         CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
-        total_debt,
+        debt_repaid,
     )?;
 
-    // Calculate liquidation bonus
-    let bonus = (loan.collateral_locked as u128 * LIQUIDATION_BONUS_BPS as u128 / 10000) as u64;
-    let collateral_to_liquidator = loan.collateral_locked.saturating_add(bonus).min(ctx.accounts.collateral_vault.amount);
+    // Liquidator gets collateral worth the debt repaid plus the bonus; an
+    // underwater loan hands over everything it has
+    let bonus = pool.collateral_for_value((debt_repaid as u128 * bonus_bps as u128 / 10000) as u64, price_oracle);
+    let collateral_to_liquidator = if shortfall > 0 {
+        collateral_amount
+    } else {
+        pool.collateral_for_value(debt_repaid, price_oracle).saturating_add(bonus).min(collateral_amount)
+    };
+
+    // Sources are drawn down in the pool's configured order
+    let (from_borrower, from_cosigner) =
+        pool.seizure_order.split(collateral_to_liquidator, borrower_amount, cosigner_amount);
 
     // Transfer collateral to liquidator (with bonus)
     let loan_key = ctx.accounts.loan.key();
@@ -93,26 +204,95 @@ pub fn handler(ctx: Context<Liquidate>) -> Result<()> {
     };
     token::transfer(
         CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, &[seeds]),
-        collateral_to_liquidator,
+        from_borrower,
     )?;
 
+    if from_cosigner > 0 {
+        if let Some(cosigner_vault) = &ctx.accounts.cosigner_vault {
+            let seeds = &[b"cosigner_vault".as_ref(), loan_key.as_ref(), &[ctx.bumps.cosigner_vault]];
+            let cpi_accounts = Transfer {
+                from: cosigner_vault.to_account_info(),
+                to: ctx.accounts.liquidator_collateral_account.to_account_info(),
+                authority: cosigner_vault.to_account_info(),
+            };
+            token::transfer(
+                CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, &[seeds]),
+                from_cosigner,
+            )?;
+        }
+    }
+
+    loan.apply_repayment(debt_repaid);
+
+    // A partial liquidation leaves the loan open with what collateral remains
+    if is_partial {
+        loan.collateral_locked = loan.collateral_locked.saturating_sub(from_borrower);
+        loan.cosigner_collateral = loan.cosigner_collateral.saturating_sub(from_cosigner);
+        ctx.accounts.pool.updated_at = clock.unix_timestamp;
+
+        msg!("Loan {} partially liquidated: {} repaid, {} remaining", loan_key, debt_repaid, loan.total_debt());
+        msg!("Collateral seized: {} (borrower {}, cosigner {})", collateral_to_liquidator, from_borrower, from_cosigner);
+        return Ok(());
+    }
+
+    // Collateral beyond what was seized goes back to whoever pledged it
+    let borrower_surplus = borrower_amount.saturating_sub(from_borrower);
+    if borrower_surplus > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.collateral_vault.to_account_info(),
+            to: ctx.accounts.borrower_collateral_account.to_account_info(),
+            authority: ctx.accounts.collateral_vault.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, &[seeds]),
+            borrower_surplus,
+        )?;
+    }
+
+    let cosigner_surplus = cosigner_amount.saturating_sub(from_cosigner);
+    if cosigner_surplus > 0 {
+        let (Some(cosigner_vault), Some(cosigner_account)) =
+            (&ctx.accounts.cosigner_vault, &ctx.accounts.cosigner_collateral_account)
+        else {
+            return err!(PrivateScoreError::InvalidCollateralAccount);
+        };
+        let seeds = &[b"cosigner_vault".as_ref(), loan_key.as_ref(), &[ctx.bumps.cosigner_vault]];
+        let cpi_accounts = Transfer {
+            from: cosigner_vault.to_account_info(),
+            to: cosigner_account.to_account_info(),
+            authority: cosigner_vault.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, &[seeds]),
+            cosigner_surplus,
+        )?;
+    }
+
     // Update loan status
     loan.status = LoanStatus::Liquidated;
     loan.closed_at = clock.unix_timestamp;
+    loan.collateral_locked = 0;
+    loan.cosigner_collateral = 0;
 
     // Update pool
     let pool = &mut ctx.accounts.pool;
-    pool.total_borrowed = pool.total_borrowed.saturating_sub(loan.principal);
-    pool.active_loans = pool.active_loans.saturating_sub(1);
+    pool.release_loan(loan.principal, loan.interest_rate)?;
+    let insurance_covered = if shortfall > 0 {
+        pool.absorb_shortfall(shortfall, principal_loss)
+    } else {
+        0
+    };
     pool.updated_at = clock.unix_timestamp;
 
     msg!("═══════════════════════════════════════════════════════════════");
     msg!("LOAN LIQUIDATED");
     msg!("═══════════════════════════════════════════════════════════════");
     msg!("Loan: {}", ctx.accounts.loan.key());
-    msg!("Debt repaid: {}", total_debt);
-    msg!("Collateral seized: {}", collateral_to_liquidator);
+    msg!("Debt repaid: {}", debt_repaid);
+    msg!("Shortfall: {} (insurance covered {})", shortfall, insurance_covered);
+    msg!("Collateral seized: {} (borrower {}, cosigner {})", collateral_to_liquidator, from_borrower, from_cosigner);
     msg!("Liquidation bonus: {}", bonus);
+    msg!("Collateral returned: {} (borrower {}, cosigner {})", borrower_surplus + cosigner_surplus, borrower_surplus, cosigner_surplus);
     msg!("═══════════════════════════════════════════════════════════════");
 
     Ok(())
@@ -0,0 +1,250 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! LIQUIDATE WITH SWAP - Repay a liquidation from the seized collateral itself
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! The keeper fronts nothing: the collateral vault PDA signs a CPI into a
+//! whitelisted DEX whose loan-mint output lands in the pool vault, and the
+//! keeper is paid only the liquidation bonus, in collateral. As in
+//! `repay_with_collateral`, both balances are measured around the swap and the
+//! slippage bounds are enforced here. Leftover collateral goes to the borrower.
+//!
+//! Cosigned loans are liquidated through `liquidate`.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{CreditRecord, GlobalConfig, LendingPool, Loan, LoanStatus, PriceFeed};
+use crate::errors::PrivateScoreError;
+use super::liquidate::LIQUIDATION_BONUS_BPS;
+
+#[derive(Accounts)]
+pub struct LiquidateWithSwap<'info> {
+    pub liquidator: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalConfig::SEED],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        mut,
+        constraint = loan.pool == pool.key() @ PrivateScoreError::InvalidAccountState,
+        constraint = loan.status == LoanStatus::Active @ PrivateScoreError::LoanNotActive,
+        constraint = !loan.has_cosigner() @ PrivateScoreError::InvalidAccountState
+    )]
+    pub loan: Account<'info, Loan>,
+
+    /// The borrower's record, to start its re-borrow cooldown. Liquidation never
+    /// depends on it: a record that no longer maps to the borrower is ignored.
+    #[account(
+        mut,
+        seeds = [b"credit", credit_record.owner.as_ref()],
+        bump = credit_record.bump
+    )]
+    pub credit_record: Option<Account<'info, CreditRecord>>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == pool.vault @ PrivateScoreError::InvalidVault
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"collateral_vault", loan.key().as_ref()],
+        bump
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+
+    /// Receives the bonus
+    #[account(
+        mut,
+        constraint = liquidator_collateral_account.mint == collateral_vault.mint @ PrivateScoreError::InvalidTokenMint
+    )]
+    pub liquidator_collateral_account: Account<'info, TokenAccount>,
+
+    /// Receives whatever collateral is left once the loan is closed
+    #[account(
+        mut,
+        constraint = borrower_collateral_account.owner == loan.borrower @ PrivateScoreError::InvalidCollateralAccount,
+        constraint = borrower_collateral_account.mint == collateral_vault.mint @ PrivateScoreError::InvalidTokenMint
+    )]
+    pub borrower_collateral_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Must be one of the DEX programs whitelisted in the global config
+    #[account(
+        constraint = global_config.is_swap_program(&swap_program.key()) @ PrivateScoreError::Unauthorized
+    )]
+    pub swap_program: UncheckedAccount<'info>,
+
+    /// Required once the pool has a price oracle, as in liquidate
+    pub price_oracle: Option<Account<'info, PriceFeed>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Route accounts for the DEX are passed as remaining_accounts
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, LiquidateWithSwap<'info>>,
+    max_collateral_in: u64,
+    min_amount_out: u64,
+    swap_data: Vec<u8>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(max_collateral_in > 0, PrivateScoreError::InvalidAmount);
+    require!(min_amount_out > 0, PrivateScoreError::InvalidAmount);
+
+    let borrow_index = ctx.accounts.pool.accrue_borrow_index(clock.unix_timestamp);
+    let loan = &mut ctx.accounts.loan;
+    let pool = &ctx.accounts.pool;
+    loan.accrue_interest(borrow_index, clock.unix_timestamp)?;
+
+    let collateral_before = ctx.accounts.collateral_vault.amount;
+    require!(collateral_before > 0, PrivateScoreError::InsufficientCollateral);
+    require!(max_collateral_in <= collateral_before, PrivateScoreError::InsufficientCollateral);
+    let collateral_value = pool.collateral_value(
+        collateral_before,
+        ctx.accounts.price_oracle.as_ref(),
+        clock.unix_timestamp,
+        ctx.accounts.global_config.max_oracle_staleness,
+    )?;
+    require!(
+        loan.is_liquidatable(
+            collateral_value,
+            loan.effective_liquidation_threshold(pool.liquidation_threshold),
+            clock.unix_timestamp,
+        ),
+        PrivateScoreError::LoanNotLiquidatable
+    );
+
+    if let Some(credit_record) = &mut ctx.accounts.credit_record {
+        if credit_record.borrower_key() == loan.borrower {
+            credit_record.start_liquidation_cooldown(pool.liquidation_cooldown, clock.unix_timestamp);
+        }
+    }
+
+    let bonus_bps = if pool.auction_liquidation {
+        require!(loan.is_in_auction(), PrivateScoreError::LoanNotLiquidatable);
+        loan.auction_bonus_bps(clock.unix_timestamp) as u64
+    } else {
+        LIQUIDATION_BONUS_BPS
+    };
+    let total_debt = loan.total_debt();
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // SWAP SEIZED COLLATERAL -> LOAN MINT
+    // ═══════════════════════════════════════════════════════════════════════
+    let vault_before = ctx.accounts.vault.amount;
+
+    let collateral_vault_key = ctx.accounts.collateral_vault.key();
+    let accounts = ctx.remaining_accounts
+        .iter()
+        .map(|account| AccountMeta {
+            pubkey: account.key(),
+            is_signer: account.is_signer || account.key() == collateral_vault_key,
+            is_writable: account.is_writable,
+        })
+        .collect();
+    let swap_ix = Instruction {
+        program_id: ctx.accounts.swap_program.key(),
+        accounts,
+        data: swap_data,
+    };
+
+    let loan_key = ctx.accounts.loan.key();
+    let seeds = &[b"collateral_vault".as_ref(), loan_key.as_ref(), &[ctx.bumps.collateral_vault]];
+    invoke_signed(&swap_ix, ctx.remaining_accounts, &[seeds])?;
+
+    ctx.accounts.collateral_vault.reload()?;
+    ctx.accounts.vault.reload()?;
+    let collateral_spent = collateral_before.saturating_sub(ctx.accounts.collateral_vault.amount);
+    let debt_repaid = ctx.accounts.vault.amount.saturating_sub(vault_before);
+
+    require!(collateral_spent <= max_collateral_in, PrivateScoreError::SlippageExceeded);
+    require!(debt_repaid >= min_amount_out, PrivateScoreError::SlippageExceeded);
+    require!(debt_repaid <= total_debt, PrivateScoreError::RepaymentExceedsDebt);
+
+    // Bonus is a share of what was seized, paid from what the swap left behind
+    let remaining_collateral = ctx.accounts.collateral_vault.amount;
+    let bonus = ((collateral_spent as u128 * bonus_bps as u128 / 10000) as u64).min(remaining_collateral);
+    let remaining_debt = total_debt - debt_repaid;
+    let leftover = remaining_collateral - bonus;
+
+    let loan = &mut ctx.accounts.loan;
+    let principal_repaid = debt_repaid.saturating_sub(loan.outstanding_interest());
+    let principal_loss = loan.outstanding_principal().saturating_sub(principal_repaid);
+    loan.apply_repayment(debt_repaid);
+
+    let closes = remaining_debt == 0 || ctx.accounts.pool.is_dust(remaining_debt) || leftover == 0;
+    if !closes {
+        // A position left open is bounded by the close factor, as in `liquidate`
+        require!(
+            debt_repaid <= ctx.accounts.pool.max_liquidation_repay(total_debt),
+            PrivateScoreError::RepaymentExceedsDebt
+        );
+    }
+
+    if bonus > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.collateral_vault.to_account_info(),
+            to: ctx.accounts.liquidator_collateral_account.to_account_info(),
+            authority: ctx.accounts.collateral_vault.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, &[seeds]),
+            bonus,
+        )?;
+    }
+
+    if !closes {
+        loan.collateral_locked = leftover;
+        ctx.accounts.pool.updated_at = clock.unix_timestamp;
+
+        msg!("Loan {} partially liquidated via swap: {} repaid, {} remaining", loan_key, debt_repaid, remaining_debt);
+        msg!("Collateral swapped: {}, keeper bonus: {}", collateral_spent, bonus);
+        return Ok(());
+    }
+
+    if leftover > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.collateral_vault.to_account_info(),
+            to: ctx.accounts.borrower_collateral_account.to_account_info(),
+            authority: ctx.accounts.collateral_vault.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, &[seeds]),
+            leftover,
+        )?;
+    }
+
+    loan.status = LoanStatus::Liquidated;
+    loan.closed_at = clock.unix_timestamp;
+    loan.collateral_locked = 0;
+
+    // Debt the swap could not cover is a shortfall; dust is simply forgiven
+    let shortfall = if ctx.accounts.pool.is_dust(remaining_debt) { 0 } else { remaining_debt };
+    let pool = &mut ctx.accounts.pool;
+    pool.release_loan(loan.principal, loan.interest_rate)?;
+    let insurance_covered = if shortfall > 0 {
+        pool.absorb_shortfall(shortfall, principal_loss)
+    } else {
+        0
+    };
+    pool.updated_at = clock.unix_timestamp;
+
+    msg!("═══════════════════════════════════════════════════════════════");
+    msg!("LOAN LIQUIDATED VIA SWAP");
+    msg!("═══════════════════════════════════════════════════════════════");
+    msg!("Loan: {}", loan_key);
+    msg!("Debt repaid: {}", debt_repaid);
+    msg!("Shortfall: {} (insurance covered {})", shortfall, insurance_covered);
+    msg!("Collateral swapped: {}, keeper bonus: {}, returned: {}", collateral_spent, bonus, leftover);
+    msg!("═══════════════════════════════════════════════════════════════");
+
+    Ok(())
+}
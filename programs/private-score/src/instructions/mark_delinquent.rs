@@ -0,0 +1,80 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! MARK DELINQUENT - Permissionless crank flagging missed installments
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! The keeper is rewarded only when the call actually flips a loan to
+//! delinquent, so repeated calls on the same loan earn nothing.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{LendingPool, Loan, LoanStatus};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct MarkDelinquent<'info> {
+    pub keeper: Signer<'info>,
+
+    #[account(mut)]
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        mut,
+        constraint = loan.pool == pool.key() @ PrivateScoreError::InvalidAccountState,
+        constraint = loan.status == LoanStatus::Active @ PrivateScoreError::LoanNotActive,
+        constraint = loan.has_installments() @ PrivateScoreError::InvalidLoanDuration
+    )]
+    pub loan: Account<'info, Loan>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == pool.vault @ PrivateScoreError::InvalidVault
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = keeper_token_account.mint == pool.loan_mint @ PrivateScoreError::InvalidTokenMint
+    )]
+    pub keeper_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<MarkDelinquent>) -> Result<()> {
+    let clock = Clock::get()?;
+    let loan = &mut ctx.accounts.loan;
+
+    loan.refresh_installments(clock.unix_timestamp);
+    // Only a loan's first delinquency is rewarded, so a borrower catching up and
+    // falling behind again cannot be farmed for keeper rewards
+    let newly_delinquent = loan.is_delinquent && !loan.ever_delinquent;
+    if newly_delinquent {
+        loan.ever_delinquent = true;
+    }
+
+    msg!("Loan {} delinquent: {}", loan.key(), loan.is_delinquent);
+    msg!("Installments paid: {}/{}, next due: {}",
+        loan.installments_paid(), loan.num_installments, loan.next_installment_due);
+
+    if newly_delinquent {
+        let reward = ctx.accounts.pool.take_keeper_reward();
+        if reward > 0 {
+            let pool = &ctx.accounts.pool;
+            let pool_id_bytes = pool.pool_id.to_le_bytes();
+            let seeds = &[b"pool".as_ref(), pool_id_bytes.as_ref(), &[pool.bump]];
+
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.keeper_token_account.to_account_info(),
+                authority: ctx.accounts.pool.to_account_info(),
+            };
+            token::transfer(
+                CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, &[seeds]),
+                reward,
+            )?;
+            msg!("Keeper reward: {}", reward);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,166 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! MIGRATE COLLATERAL MINT - Move a loan's collateral to a successor mint
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! For token migrations with a 1:1 swap. The pool authority takes the old
+//! collateral and supplies the same amount of the new mint, which must be the
+//! successor registered for the old mint under the pool's multisig. The collateral
+//! vault is closed and re-created at the same PDA for the new mint, so
+//! repay, liquidate and sweep_dust keep finding it with the usual seeds.
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, CreateAccount};
+use anchor_spl::token::{self, CloseAccount, InitializeAccount3, Mint, Token, TokenAccount, Transfer};
+use crate::state::{AdminMultisig, CollateralWhitelist, LendingPool, Loan, LoanStatus, MintSuccessor};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct MigrateCollateralMint<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = pool.authority == authority.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub pool: Account<'info, LendingPool>,
+
+    /// Cosigned loans and loans under auction are left alone
+    #[account(
+        mut,
+        constraint = loan.pool == pool.key() @ PrivateScoreError::InvalidAccountState,
+        constraint = loan.status == LoanStatus::Active @ PrivateScoreError::LoanNotActive,
+        constraint = !loan.has_cosigner() @ PrivateScoreError::InvalidAccountState,
+        constraint = !loan.is_in_auction() @ PrivateScoreError::InvalidAccountState
+    )]
+    pub loan: Account<'info, Loan>,
+
+    #[account(
+        mut,
+        seeds = [b"collateral_vault", loan.key().as_ref()],
+        bump,
+        constraint = collateral_vault.mint == loan.collateral_mint @ PrivateScoreError::InvalidTokenMint
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+
+    /// Extra collateral mints accepted by the pool, if it has a whitelist
+    #[account(
+        seeds = [CollateralWhitelist::SEED_PREFIX, pool.key().as_ref()],
+        bump = collateral_whitelist.bump
+    )]
+    pub collateral_whitelist: Option<Account<'info, CollateralWhitelist>>,
+
+    #[account(
+        seeds = [MintSuccessor::SEED_PREFIX, pool.key().as_ref(), loan.collateral_mint.as_ref()],
+        bump = mint_successor.bump
+    )]
+    pub mint_successor: Account<'info, MintSuccessor>,
+
+    /// The registered successor; must already be accepted by the pool and share
+    /// its collateral decimals. A price feed only prices the pool's own mint.
+    #[account(
+        constraint = new_mint.key() == mint_successor.new_mint @ PrivateScoreError::InvalidTokenMint,
+        constraint = !pool.has_price_oracle() || new_mint.key() == pool.collateral_mint @ PrivateScoreError::InvalidOracle,
+        constraint = new_mint.decimals == pool.collateral_decimals @ PrivateScoreError::InvalidTokenMint,
+        constraint = pool.accepts_collateral(&new_mint.key(), collateral_whitelist.as_deref()) @ PrivateScoreError::InvalidTokenMint
+    )]
+    pub new_mint: Account<'info, Mint>,
+
+    /// Receives the old collateral
+    #[account(
+        mut,
+        constraint = authority_old_collateral_account.mint == loan.collateral_mint @ PrivateScoreError::InvalidTokenMint,
+        constraint = authority_old_collateral_account.owner == authority.key() @ PrivateScoreError::InvalidCollateralAccount
+    )]
+    pub authority_old_collateral_account: Account<'info, TokenAccount>,
+
+    /// Supplies the replacement collateral
+    #[account(
+        mut,
+        constraint = authority_new_collateral_account.mint == new_mint.key() @ PrivateScoreError::InvalidTokenMint,
+        constraint = authority_new_collateral_account.owner == authority.key() @ PrivateScoreError::InvalidCollateralAccount
+    )]
+    pub authority_new_collateral_account: Account<'info, TokenAccount>,
+
+    /// Required when the pool has an admin multisig; approvers sign via remaining_accounts
+    pub admin_multisig: Option<Account<'info, AdminMultisig>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<MigrateCollateralMint>) -> Result<()> {
+    ctx.accounts.pool.require_admin_approval(ctx.accounts.admin_multisig.as_ref(), ctx.remaining_accounts)?;
+
+    let amount = ctx.accounts.collateral_vault.amount;
+    require!(amount >= ctx.accounts.loan.collateral_locked, PrivateScoreError::InsufficientCollateral);
+    require!(
+        ctx.accounts.authority_new_collateral_account.amount >= amount,
+        PrivateScoreError::InsufficientCollateral
+    );
+
+    let loan_key = ctx.accounts.loan.key();
+    let seeds = &[b"collateral_vault".as_ref(), loan_key.as_ref(), &[ctx.bumps.collateral_vault]];
+    let vault_info = ctx.accounts.collateral_vault.to_account_info();
+
+    // Empty and close the old vault
+    if amount > 0 {
+        let cpi_accounts = Transfer {
+            from: vault_info.clone(),
+            to: ctx.accounts.authority_old_collateral_account.to_account_info(),
+            authority: vault_info.clone(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, &[seeds]),
+            amount,
+        )?;
+    }
+
+    let cpi_accounts = CloseAccount {
+        account: vault_info.clone(),
+        destination: ctx.accounts.authority.to_account_info(),
+        authority: vault_info.clone(),
+    };
+    token::close_account(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, &[seeds]),
+    )?;
+
+    // Re-create it at the same address for the new mint; the authority pays the
+    // rent it was just refunded
+    let cpi_accounts = CreateAccount {
+        from: ctx.accounts.authority.to_account_info(),
+        to: vault_info.clone(),
+    };
+    system_program::create_account(
+        CpiContext::new_with_signer(ctx.accounts.system_program.to_account_info(), cpi_accounts, &[seeds]),
+        Rent::get()?.minimum_balance(TokenAccount::LEN),
+        TokenAccount::LEN as u64,
+        &ctx.accounts.token_program.key(),
+    )?;
+
+    let cpi_accounts = InitializeAccount3 {
+        account: vault_info.clone(),
+        mint: ctx.accounts.new_mint.to_account_info(),
+        authority: vault_info.clone(),
+    };
+    token::initialize_account3(CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts))?;
+
+    // Refill it 1:1 with the new mint
+    if amount > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.authority_new_collateral_account.to_account_info(),
+            to: vault_info,
+            authority: ctx.accounts.authority.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
+            amount,
+        )?;
+    }
+
+    let loan = &mut ctx.accounts.loan;
+    let old_mint = loan.collateral_mint;
+    loan.collateral_mint = ctx.accounts.new_mint.key();
+
+    msg!("Loan {} collateral migrated from {} to {} ({} units)", loan_key, old_mint, loan.collateral_mint, amount);
+    Ok(())
+}
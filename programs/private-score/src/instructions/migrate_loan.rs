@@ -0,0 +1,189 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! MIGRATE LOAN - Move an active loan from a deprecated pool to a successor
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! The borrower settles interest owed to the source pool and the target pool
+//! refinances the outstanding principal into the source vault, so source
+//! lenders are made whole and the target takes over only the principal. The
+//! loan account keeps its original PDA; only its `pool` reference changes.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{Blocklist, CreditRecord, GlobalConfig, LendingPool, Loan, LoanStatus, PriceFeed};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct MigrateLoan<'info> {
+    pub borrower: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalConfig::SEED],
+        bump = global_config.bump,
+        constraint = !global_config.global_paused @ PrivateScoreError::ProtocolPaused
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        constraint = !source_pool.is_active @ PrivateScoreError::InvalidAccountState
+    )]
+    pub source_pool: Account<'info, LendingPool>,
+
+    #[account(
+        mut,
+        constraint = target_pool.key() != source_pool.key() @ PrivateScoreError::InvalidAccountState,
+        constraint = target_pool.is_active @ PrivateScoreError::PoolInactive,
+        constraint = target_pool.loan_mint == source_pool.loan_mint @ PrivateScoreError::InvalidTokenMint,
+        constraint = target_pool.collateral_mint == loan.collateral_mint @ PrivateScoreError::InvalidTokenMint
+    )]
+    pub target_pool: Account<'info, LendingPool>,
+
+    #[account(
+        mut,
+        constraint = loan.borrower == borrower.key() @ PrivateScoreError::Unauthorized,
+        constraint = loan.pool == source_pool.key() @ PrivateScoreError::InvalidAccountState,
+        constraint = loan.status == LoanStatus::Active @ PrivateScoreError::LoanNotActive
+    )]
+    pub loan: Account<'info, Loan>,
+
+    /// Read for the target pool's `min_tier_for_access` and the post-liquidation cooldown
+    #[account(
+        seeds = [b"credit", credit_record.owner.as_ref()],
+        bump = credit_record.bump,
+        constraint = credit_record.borrower_key() == borrower.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub credit_record: Account<'info, CreditRecord>,
+
+    /// Required once the target pool has a blocklist
+    #[account(
+        seeds = [Blocklist::SEED_PREFIX, target_pool.key().as_ref()],
+        bump = target_blocklist.bump
+    )]
+    pub target_blocklist: Option<Account<'info, Blocklist>>,
+
+    /// Required once the target pool has a price oracle
+    pub target_price_feed: Option<Account<'info, PriceFeed>>,
+
+    #[account(
+        mut,
+        constraint = borrower_token_account.mint == source_pool.loan_mint @ PrivateScoreError::InvalidTokenMint,
+        constraint = borrower_token_account.owner == borrower.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub borrower_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = source_vault.key() == source_pool.vault @ PrivateScoreError::InvalidVault
+    )]
+    pub source_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = target_vault.key() == target_pool.vault @ PrivateScoreError::InvalidVault
+    )]
+    pub target_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"collateral_vault", loan.key().as_ref()],
+        bump
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<MigrateLoan>) -> Result<()> {
+    let clock = Clock::get()?;
+    let source_index = ctx.accounts.source_pool.accrue_borrow_index(clock.unix_timestamp);
+    let target_index = ctx.accounts.target_pool.accrue_borrow_index(clock.unix_timestamp);
+    let loan = &mut ctx.accounts.loan;
+    let target = &ctx.accounts.target_pool;
+
+    target.require_not_blocked(ctx.accounts.target_blocklist.as_ref(), &ctx.accounts.borrower.key())?;
+    require!(target.tier_has_access(ctx.accounts.credit_record.tier), PrivateScoreError::TierDoesNotQualify);
+    // Refinancing into a new pool is a fresh borrow there
+    require!(
+        !ctx.accounts.credit_record.in_liquidation_cooldown(clock.unix_timestamp),
+        PrivateScoreError::BorrowCooldownActive
+    );
+
+    // Settle interest owed to the source pool before switching terms
+    loan.accrue_interest(source_index, clock.unix_timestamp)?;
+    let settled_interest = loan.outstanding_interest();
+    if settled_interest > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.borrower_token_account.to_account_info(),
+            to: ctx.accounts.source_vault.to_account_info(),
+            authority: ctx.accounts.borrower.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
+            settled_interest,
+        )?;
+        loan.apply_repayment(settled_interest);
+    }
+
+    // The loan must be healthy under the target's collateral ratio
+    let is_credit_verified = loan.is_credit_verified();
+    let required_collateral = target.get_required_collateral(loan.total_debt(), is_credit_verified);
+    require!(
+        ctx.accounts.collateral_vault.amount >= required_collateral,
+        PrivateScoreError::HealthFactorTooLow
+    );
+    target.require_collateral_value(
+        &loan.collateral_mint,
+        ctx.accounts.collateral_vault.amount,
+        loan.total_debt(),
+        target.get_collateral_ratio(is_credit_verified),
+        ctx.accounts.target_price_feed.as_ref(),
+        clock.unix_timestamp,
+        ctx.accounts.global_config.max_oracle_staleness,
+    )?;
+
+    let refinanced = loan.outstanding_principal();
+    require!(target.within_borrow_cap(refinanced), PrivateScoreError::BorrowLimitExceeded);
+    require!(target.has_liquidity(refinanced, ctx.accounts.target_vault.amount), PrivateScoreError::InsufficientLiquidity);
+
+    // Target pool pays off the source pool's outstanding principal
+    if refinanced > 0 {
+        let pool_id_bytes = target.pool_id.to_le_bytes();
+        let seeds = &[b"pool".as_ref(), pool_id_bytes.as_ref(), &[target.bump]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.target_vault.to_account_info(),
+            to: ctx.accounts.source_vault.to_account_info(),
+            authority: ctx.accounts.target_pool.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, &[seeds]),
+            refinanced,
+        )?;
+    }
+
+    // Re-point the loan and adopt the target's terms
+    let source_rate = loan.interest_rate;
+    let target = &ctx.accounts.target_pool;
+    loan.pool = target.key();
+    loan.collateral_ratio = target.get_collateral_ratio(is_credit_verified);
+    loan.interest_rate = target.borrow_rate();
+    loan.entry_index = target_index;
+    loan.max_interest_multiple = target.max_interest_multiple;
+
+    let source = &mut ctx.accounts.source_pool;
+    source.release_loan(loan.principal, source_rate)?;
+    source.settle_interest(loan.interest_accrued);
+    source.updated_at = clock.unix_timestamp;
+
+    // Interest is fully settled with the source; the target's ledger starts at zero
+    loan.amount_repaid = loan.amount_repaid.saturating_sub(loan.interest_repaid);
+    loan.interest_accrued = 0;
+    loan.interest_repaid = 0;
+
+    let target = &mut ctx.accounts.target_pool;
+    target.open_loan(loan.principal, loan.interest_rate);
+    target.updated_at = clock.unix_timestamp;
+
+    msg!("Loan {} migrated to pool {}", loan.key(), target.pool_id);
+    msg!("Refinanced principal: {}, interest settled: {}", refinanced, settled_interest);
+    Ok(())
+}
@@ -1,25 +1,159 @@
 //! Instructions module - All program instructions for PrivateScore
 
+pub mod accrue_interest;
+pub mod add_alt_repay_token;
+pub mod add_collateral_mint;
+pub mod audit_commitment;
+pub mod block_address;
 pub mod borrow_standard;
+pub mod borrow_with_receipt;
+pub mod cancel_liquidation_auction;
+pub mod cancel_recovery;
+pub mod complete_recovery;
+pub mod create_admin_multisig;
+pub mod create_blocklist;
+pub mod create_collateral_whitelist;
+pub mod create_price_feed;
+pub mod create_proof_replay_cache;
+pub mod create_tier_table;
 pub mod deposit;
+pub mod deposit_batch;
+pub mod disclose_credit;
+pub mod disclose_predicate;
+pub mod emergency_withdraw;
+pub mod export_credit_history;
+pub mod freeze_credit_record;
+pub mod fund_insurance;
 pub mod grant_viewing_access;
+pub mod initialize_global_config;
 pub mod initialize_pool;
+pub mod initiate_recovery;
+pub mod is_liquidatable;
 pub mod liquidate;
+pub mod liquidate_with_swap;
+pub mod mark_delinquent;
+pub mod migrate_collateral_mint;
+pub mod migrate_loan;
+pub mod open_lender_position;
+pub mod open_pool_snapshots;
+pub mod pause_disclosures;
+pub mod preview_terms;
+pub mod quote_borrow;
+pub mod quote_rates;
 pub mod register_credit;
+pub mod register_credit_batch;
+pub mod register_mint_successor;
+pub mod reinvest_reserves;
+pub mod release_upgraded_collateral;
+pub mod remove_alt_repay_token;
+pub mod remove_collateral_mint;
 pub mod repay;
+pub mod repay_with_alt_token;
+pub mod repay_with_collateral;
+pub mod resume_disclosures;
+pub mod revoke_all_viewing_access;
 pub mod revoke_viewing_access;
+pub mod self_disclose;
+pub mod set_auto_renew;
+pub mod set_beneficiary;
+pub mod set_borrow_delegate;
+pub mod set_emergency_mode;
+pub mod set_expiry_warning_window;
+pub mod set_global_pause;
+pub mod set_max_viewing_keys;
+pub mod set_pool_active;
+pub mod set_price_oracle;
+pub mod set_recovery_authority;
+pub mod snapshot_pool;
+pub mod start_liquidation_auction;
+pub mod submit_proof;
+pub mod sweep_dust;
+pub mod unblock_address;
+pub mod unfreeze_credit_record;
 pub mod update_credit;
+pub mod update_global_config;
+pub mod update_pool_params;
+pub mod update_price;
+pub mod update_tier_table;
 pub mod verify_and_borrow;
 pub mod withdraw;
+pub mod withdraw_batch;
 
+pub use accrue_interest::*;
+pub use add_alt_repay_token::*;
+pub use add_collateral_mint::*;
+pub use audit_commitment::*;
+pub use block_address::*;
 pub use borrow_standard::*;
+pub use borrow_with_receipt::*;
+pub use cancel_liquidation_auction::*;
+pub use cancel_recovery::*;
+pub use complete_recovery::*;
+pub use create_admin_multisig::*;
+pub use create_blocklist::*;
+pub use create_collateral_whitelist::*;
+pub use create_price_feed::*;
+pub use create_proof_replay_cache::*;
+pub use create_tier_table::*;
 pub use deposit::*;
+pub use deposit_batch::*;
+pub use disclose_credit::*;
+pub use disclose_predicate::*;
+pub use emergency_withdraw::*;
+pub use export_credit_history::*;
+pub use freeze_credit_record::*;
+pub use fund_insurance::*;
 pub use grant_viewing_access::*;
+pub use initialize_global_config::*;
 pub use initialize_pool::*;
+pub use initiate_recovery::*;
+pub use is_liquidatable::*;
 pub use liquidate::*;
+pub use liquidate_with_swap::*;
+pub use mark_delinquent::*;
+pub use migrate_collateral_mint::*;
+pub use migrate_loan::*;
+pub use open_lender_position::*;
+pub use open_pool_snapshots::*;
+pub use pause_disclosures::*;
+pub use preview_terms::*;
+pub use quote_borrow::*;
+pub use quote_rates::*;
 pub use register_credit::*;
+pub use register_credit_batch::*;
+pub use register_mint_successor::*;
+pub use reinvest_reserves::*;
+pub use release_upgraded_collateral::*;
+pub use remove_alt_repay_token::*;
+pub use remove_collateral_mint::*;
 pub use repay::*;
+pub use repay_with_alt_token::*;
+pub use repay_with_collateral::*;
+pub use resume_disclosures::*;
+pub use revoke_all_viewing_access::*;
 pub use revoke_viewing_access::*;
+pub use self_disclose::*;
+pub use set_auto_renew::*;
+pub use set_beneficiary::*;
+pub use set_borrow_delegate::*;
+pub use set_emergency_mode::*;
+pub use set_expiry_warning_window::*;
+pub use set_global_pause::*;
+pub use set_max_viewing_keys::*;
+pub use set_pool_active::*;
+pub use set_price_oracle::*;
+pub use set_recovery_authority::*;
+pub use snapshot_pool::*;
+pub use start_liquidation_auction::*;
+pub use submit_proof::*;
+pub use sweep_dust::*;
+pub use unblock_address::*;
+pub use unfreeze_credit_record::*;
 pub use update_credit::*;
+pub use update_global_config::*;
+pub use update_pool_params::*;
+pub use update_price::*;
+pub use update_tier_table::*;
 pub use verify_and_borrow::*;
-pub use withdraw::*;
\ No newline at end of file
+pub use withdraw::*;
+pub use withdraw_batch::*;
\ No newline at end of file
@@ -0,0 +1,39 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! OPEN LENDER POSITION - Create a lender's position account for a pool
+//! ═══════════════════════════════════════════════════════════════════════════
+
+use anchor_lang::prelude::*;
+use crate::state::{LenderPosition, LendingPool};
+
+#[derive(Accounts)]
+pub struct OpenLenderPosition<'info> {
+    #[account(mut)]
+    pub lender: Signer<'info>,
+
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        init,
+        payer = lender,
+        space = LenderPosition::SIZE,
+        seeds = [LenderPosition::SEED_PREFIX, pool.key().as_ref(), lender.key().as_ref()],
+        bump
+    )]
+    pub lender_position: Account<'info, LenderPosition>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<OpenLenderPosition>) -> Result<()> {
+    let clock = Clock::get()?;
+    let position = &mut ctx.accounts.lender_position;
+
+    position.owner = ctx.accounts.lender.key();
+    position.pool = ctx.accounts.pool.key();
+    position.created_at = clock.unix_timestamp;
+    position.last_update = clock.unix_timestamp;
+    position.bump = ctx.bumps.lender_position;
+
+    msg!("Lender position opened for {} in pool {}", position.owner, ctx.accounts.pool.pool_id);
+    Ok(())
+}
@@ -0,0 +1,34 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! OPEN POOL SNAPSHOTS - Create the snapshot ring account for a pool
+//! ═══════════════════════════════════════════════════════════════════════════
+
+use anchor_lang::prelude::*;
+use crate::state::{LendingPool, PoolSnapshots};
+
+#[derive(Accounts)]
+pub struct OpenPoolSnapshots<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = PoolSnapshots::LEN,
+        seeds = [PoolSnapshots::SEED_PREFIX, pool.key().as_ref()],
+        bump
+    )]
+    pub pool_snapshots: Account<'info, PoolSnapshots>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<OpenPoolSnapshots>) -> Result<()> {
+    let snapshots = &mut ctx.accounts.pool_snapshots;
+    snapshots.pool = ctx.accounts.pool.key();
+    snapshots.bump = ctx.bumps.pool_snapshots;
+
+    msg!("Snapshot ring opened for pool {}", ctx.accounts.pool.pool_id);
+    Ok(())
+}
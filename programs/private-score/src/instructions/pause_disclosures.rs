@@ -0,0 +1,30 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! PAUSE DISCLOSURES - Temporarily block all viewing keys without revoking
+//! ═══════════════════════════════════════════════════════════════════════════
+
+use anchor_lang::prelude::*;
+use crate::state::CreditRecord;
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct PauseDisclosures<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"credit", owner.key().as_ref()],
+        bump = credit_record.bump,
+        constraint = credit_record.owner == owner.key() @ PrivateScoreError::Unauthorized,
+        constraint = !credit_record.disclosure_paused @ PrivateScoreError::InvalidAccountState
+    )]
+    pub credit_record: Account<'info, CreditRecord>,
+}
+
+pub fn handler(ctx: Context<PauseDisclosures>) -> Result<()> {
+    let credit_record = &mut ctx.accounts.credit_record;
+    credit_record.disclosure_paused = true;
+    credit_record.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Disclosures for {} paused: {}", credit_record.owner, true);
+    Ok(())
+}
@@ -0,0 +1,58 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! PREVIEW TERMS - Effective borrow terms a credit tier would receive
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! Read-only: mirrors the tier adjustments verify_and_borrow applies, so a
+//! front-end can show the terms before the borrower generates a proof.
+
+use anchor_lang::prelude::*;
+use crate::state::{CreditTier, LendingPool};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct PreviewTerms<'info> {
+    pub pool: Account<'info, LendingPool>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TermsPreview {
+    pub tier: u8,
+    pub amount: u64,
+    pub collateral_ratio: u16,
+    pub required_collateral: u64,
+    pub interest_rate: u16,
+    pub liquidation_threshold: u16,
+    pub max_borrow_bps: u16,
+    pub min_collateral_balance: u64,     // Balance the borrower must pledge to pass the tier borrow limit
+}
+
+pub fn handler(ctx: Context<PreviewTerms>, tier: u8, amount: u64) -> Result<TermsPreview> {
+    require!(amount > 0, PrivateScoreError::InvalidAmount);
+    require!(ctx.accounts.pool.accepts_credit_loans, PrivateScoreError::CreditLoansNotAccepted);
+    let credit_tier = CreditTier::from_u8(tier);
+    require!(credit_tier != CreditTier::Unknown, PrivateScoreError::TierDoesNotQualify);
+
+    let pool = &ctx.accounts.pool;
+    let collateral_ratio = pool.collateral_ratio_for_tier(credit_tier);
+    let required_collateral = pool.required_collateral_at(amount, collateral_ratio);
+
+    // Smallest pledge for which amount <= max_borrow_for_tier(tier, pledge)
+    let max_borrow_bps = pool.tier_max_borrow_bps[tier as usize];
+    let min_collateral_balance = if max_borrow_bps == 0 {
+        u64::MAX
+    } else {
+        let limit_basis = (amount as u128 * 10000).div_ceil(max_borrow_bps as u128).min(u64::MAX as u128) as u64;
+        pool.to_collateral_units(limit_basis).max(required_collateral)
+    };
+
+    Ok(TermsPreview {
+        tier,
+        amount,
+        collateral_ratio,
+        required_collateral,
+        interest_rate: pool.borrow_rate(),
+        liquidation_threshold: pool.liquidation_threshold_for_tier(credit_tier),
+        max_borrow_bps,
+        min_collateral_balance,
+    })
+}
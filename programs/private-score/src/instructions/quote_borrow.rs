@@ -0,0 +1,40 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! QUOTE BORROW - Preview required collateral and savings before borrowing
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! Read-only: returns the same figures the borrow instructions will lock.
+//! Collateral is denominated in token units, matching the borrow handlers.
+
+use anchor_lang::prelude::*;
+use crate::state::LendingPool;
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct QuoteBorrow<'info> {
+    pub pool: Account<'info, LendingPool>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BorrowQuote {
+    pub amount: u64,
+    pub collateral_ratio: u16,
+    pub required_collateral: u64,
+    pub standard_collateral: u64,
+    pub collateral_savings: u64,
+}
+
+pub fn handler(ctx: Context<QuoteBorrow>, amount: u64, is_credit_verified: bool) -> Result<BorrowQuote> {
+    require!(amount > 0, PrivateScoreError::InvalidAmount);
+
+    let pool = &ctx.accounts.pool;
+    let required_collateral = pool.get_required_collateral(amount, is_credit_verified);
+    let standard_collateral = pool.get_required_collateral(amount, false);
+
+    Ok(BorrowQuote {
+        amount,
+        collateral_ratio: pool.get_collateral_ratio(is_credit_verified),
+        required_collateral,
+        standard_collateral,
+        collateral_savings: standard_collateral.saturating_sub(required_collateral),
+    })
+}
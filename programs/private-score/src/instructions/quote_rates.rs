@@ -0,0 +1,31 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! QUOTE RATES - Current borrow and supply APY for a pool
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! Read-only. The spread between the two is the idle share of deposits plus
+//! the insurance fee taken from repaid interest.
+
+use anchor_lang::prelude::*;
+use crate::state::LendingPool;
+
+#[derive(Accounts)]
+pub struct QuoteRates<'info> {
+    pub pool: Account<'info, LendingPool>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RateQuote {
+    pub utilization_rate: u16,
+    pub borrow_rate: u16,
+    pub supply_rate: u16,
+}
+
+pub fn handler(ctx: Context<QuoteRates>) -> Result<RateQuote> {
+    let pool = &ctx.accounts.pool;
+
+    Ok(RateQuote {
+        utilization_rate: pool.utilization_rate(),
+        borrow_rate: pool.borrow_rate(),
+        supply_rate: pool.supply_rate(),
+    })
+}
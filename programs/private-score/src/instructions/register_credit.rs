@@ -27,11 +27,17 @@ pub fn handler(
     ctx: Context<RegisterCredit>,
     commitment: [u8; 32],
     tier: u8,
+    expiry_duration: i64,
 ) -> Result<()> {
     // Validate commitment is not empty
     require!(commitment != [0u8; 32], PrivateScoreError::InvalidCommitment);
+
+    // Zero selects the default validity window
+    let expiry_duration = if expiry_duration == 0 { CreditRecord::DEFAULT_EXPIRY_DURATION } else { expiry_duration };
+    require!(expiry_duration >= CreditRecord::MIN_EXPIRY_DURATION, PrivateScoreError::InvalidExpiry);
+    require!(expiry_duration <= CreditRecord::MAX_EXPIRY_DURATION, PrivateScoreError::ExpiryTooLong);
     
-    let tier_enum = CreditTier::from_u8(tier);
+    let tier_enum = CreditTier::try_from_u8(tier)?;
     let clock = Clock::get()?;
 
     let credit_record = &mut ctx.accounts.credit_record;
@@ -41,16 +47,20 @@ pub fn handler(
     credit_record.nonce = 1;
     credit_record.registered_at = clock.unix_timestamp;
     credit_record.updated_at = clock.unix_timestamp;
-    credit_record.expires_at = clock.unix_timestamp + CreditRecord::DEFAULT_EXPIRY_DURATION;
+    credit_record.expires_at = clock.unix_timestamp + expiry_duration;
     credit_record.proofs_verified = 0;
     credit_record.loans_taken = 0;
     credit_record.total_borrowed = 0;
     credit_record.total_repaid = 0;
     credit_record.on_time_repayments = 0;
     credit_record.late_repayments = 0;
+    credit_record.active_viewing_keys = 0;
+    credit_record.max_viewing_keys = CreditRecord::DEFAULT_MAX_VIEWING_KEYS;
     credit_record.is_active = true;
+    credit_record.frozen = false;
     credit_record.disclosure_enabled = false;
     credit_record.is_compressed = false;
+    credit_record.auto_renew = false;
     credit_record.bump = ctx.bumps.credit_record;
 
     msg!("Credit commitment registered for {}", ctx.accounts.owner.key());
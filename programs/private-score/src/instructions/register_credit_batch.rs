@@ -0,0 +1,119 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! REGISTER CREDIT BATCH - Scoring provider onboards a cohort in one transaction
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! `remaining_accounts` holds the credit record PDA of each entry, in order
+//! (all writable). Records are created and filled exactly as in
+//! `register_credit`, with the default expiry, and paid for by the provider.
+//! Owners that already have a record are skipped rather than failing the batch.
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+use crate::state::{CreditRecord, CreditTier, GlobalConfig};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct RegisterCreditBatch<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalConfig::SEED],
+        bump = global_config.bump,
+        constraint = global_config.is_score_provider(&provider.key()) @ PrivateScoreError::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreditRegistration {
+    pub owner: Pubkey,
+    pub commitment: [u8; 32],
+    pub tier: u8,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RegisterCreditBatch<'info>>,
+    entries: Vec<CreditRegistration>,
+) -> Result<()> {
+    require!(!entries.is_empty(), PrivateScoreError::InvalidAmount);
+    require!(ctx.remaining_accounts.len() == entries.len(), PrivateScoreError::InvalidAccountState);
+
+    let clock = Clock::get()?;
+    let lamports = Rent::get()?.minimum_balance(CreditRecord::LEN);
+    let mut registered: u32 = 0;
+
+    for (entry, record_info) in entries.iter().zip(ctx.remaining_accounts.iter()) {
+        require!(entry.commitment != [0u8; 32], PrivateScoreError::InvalidCommitment);
+        let tier = CreditTier::try_from_u8(entry.tier)?;
+
+        let (expected, bump) = Pubkey::find_program_address(&[b"credit", entry.owner.as_ref()], ctx.program_id);
+        require_keys_eq!(record_info.key(), expected, PrivateScoreError::InvalidAccountState);
+
+        if record_info.owner == ctx.program_id {
+            msg!("Credit record for {} already exists, skipped", entry.owner);
+            continue;
+        }
+
+        let seeds = &[b"credit".as_ref(), entry.owner.as_ref(), &[bump]];
+        if record_info.lamports() == 0 {
+            let cpi_accounts = CreateAccount {
+                from: ctx.accounts.provider.to_account_info(),
+                to: record_info.clone(),
+            };
+            system_program::create_account(
+                CpiContext::new_with_signer(ctx.accounts.system_program.to_account_info(), cpi_accounts, &[seeds]),
+                lamports,
+                CreditRecord::LEN as u64,
+                ctx.program_id,
+            )?;
+        } else {
+            // Anyone can send lamports to the PDA first, which makes create_account
+            // fail; top it up to rent-exempt and claim it piecewise instead
+            let shortfall = lamports.saturating_sub(record_info.lamports());
+            if shortfall > 0 {
+                let cpi_accounts = Transfer {
+                    from: ctx.accounts.provider.to_account_info(),
+                    to: record_info.clone(),
+                };
+                system_program::transfer(
+                    CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts),
+                    shortfall,
+                )?;
+            }
+            let cpi_accounts = Allocate { account_to_allocate: record_info.clone() };
+            system_program::allocate(
+                CpiContext::new_with_signer(ctx.accounts.system_program.to_account_info(), cpi_accounts, &[seeds]),
+                CreditRecord::LEN as u64,
+            )?;
+            let cpi_accounts = Assign { account_to_assign: record_info.clone() };
+            system_program::assign(
+                CpiContext::new_with_signer(ctx.accounts.system_program.to_account_info(), cpi_accounts, &[seeds]),
+                ctx.program_id,
+            )?;
+        }
+
+        let credit_record = CreditRecord {
+            owner: entry.owner,
+            commitment: entry.commitment,
+            tier,
+            nonce: 1,
+            registered_at: clock.unix_timestamp,
+            updated_at: clock.unix_timestamp,
+            expires_at: clock.unix_timestamp + CreditRecord::DEFAULT_EXPIRY_DURATION,
+            max_viewing_keys: CreditRecord::DEFAULT_MAX_VIEWING_KEYS,
+            is_active: true,
+            bump,
+            ..Default::default()
+        };
+        credit_record.try_serialize(&mut &mut record_info.try_borrow_mut_data()?[..])?;
+        registered += 1;
+
+        msg!("Credit commitment registered for {} (tier {})", entry.owner, tier.to_u8());
+    }
+
+    msg!("Batch registered {} of {} records", registered, entries.len());
+    Ok(())
+}
@@ -0,0 +1,58 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! REGISTER MINT SUCCESSOR - Name the mint a retired collateral mint migrates to
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! Swapping borrowers' collateral is only safe under shared control, so the
+//! pool must have an admin multisig and its approvers must sign.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::state::{AdminMultisig, LendingPool, MintSuccessor};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+#[instruction(old_mint: Pubkey)]
+pub struct RegisterMintSuccessor<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = pool.authority == authority.key() @ PrivateScoreError::Unauthorized,
+        constraint = pool.has_admin_multisig() @ PrivateScoreError::Unauthorized
+    )]
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = MintSuccessor::LEN,
+        seeds = [MintSuccessor::SEED_PREFIX, pool.key().as_ref(), old_mint.as_ref()],
+        bump
+    )]
+    pub mint_successor: Account<'info, MintSuccessor>,
+
+    #[account(
+        constraint = new_mint.key() != old_mint @ PrivateScoreError::InvalidTokenMint,
+        constraint = new_mint.decimals == pool.collateral_decimals @ PrivateScoreError::InvalidTokenMint
+    )]
+    pub new_mint: Account<'info, Mint>,
+
+    /// Approvers sign via remaining_accounts
+    pub admin_multisig: Option<Account<'info, AdminMultisig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RegisterMintSuccessor>, old_mint: Pubkey) -> Result<()> {
+    ctx.accounts.pool.require_admin_approval(ctx.accounts.admin_multisig.as_ref(), ctx.remaining_accounts)?;
+
+    let successor = &mut ctx.accounts.mint_successor;
+    successor.pool = ctx.accounts.pool.key();
+    successor.old_mint = old_mint;
+    successor.new_mint = ctx.accounts.new_mint.key();
+    successor.registered_at = Clock::get()?.unix_timestamp;
+    successor.bump = ctx.bumps.mint_successor;
+
+    msg!("Pool {}: collateral mint {} succeeded by {}", ctx.accounts.pool.pool_id, old_mint, successor.new_mint);
+    Ok(())
+}
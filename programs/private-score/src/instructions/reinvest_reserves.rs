@@ -0,0 +1,39 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! REINVEST RESERVES - Recycle protocol reserves into lendable liquidity
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! Tokens stay in the vault; only the accounting moves. Reinvested reserves
+//! are tracked as protocol-owned deposits so lenders' positions are unchanged.
+
+use anchor_lang::prelude::*;
+use crate::state::{AdminMultisig, LendingPool};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct ReinvestReserves<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub pool: Account<'info, LendingPool>,
+
+    /// Required when the pool has an admin multisig; approvers sign via remaining_accounts
+    pub admin_multisig: Option<Account<'info, AdminMultisig>>,
+}
+
+pub fn handler(ctx: Context<ReinvestReserves>, amount: u64) -> Result<()> {
+    require!(amount > 0, PrivateScoreError::InvalidAmount);
+    ctx.accounts.pool.require_admin_approval(ctx.accounts.admin_multisig.as_ref(), ctx.remaining_accounts)?;
+
+    let clock = Clock::get()?;
+    let pool = &mut ctx.accounts.pool;
+    require!(pool.protocol_reserves > 0, PrivateScoreError::InvalidAmount);
+    pool.accrue_borrow_index(clock.unix_timestamp);
+    let reinvested = pool.reinvest_reserves(amount);
+    pool.updated_at = clock.unix_timestamp;
+
+    msg!("Pool {} reinvested {} of reserves ({} remaining)", pool.pool_id, reinvested, pool.protocol_reserves);
+    Ok(())
+}
@@ -0,0 +1,132 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! RELEASE UPGRADED COLLATERAL - Free collateral after a credit tier upgrade
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! A borrower whose tier improved mid-loan re-proves against the updated
+//! commitment; the loan moves to the new tier's ratio and any collateral above
+//! that requirement is returned without refinancing.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{CreditRecord, GlobalConfig, LendingPool, Loan, LoanStatus, PriceFeed, ProofReplayCache};
+use crate::errors::PrivateScoreError;
+use super::verify_and_borrow::verify_credit_proof;
+
+#[derive(Accounts)]
+pub struct ReleaseUpgradedCollateral<'info> {
+    pub borrower: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalConfig::SEED],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        mut,
+        seeds = [b"credit", borrower.key().as_ref()],
+        bump = credit_record.bump,
+        constraint = credit_record.owner == borrower.key() @ PrivateScoreError::Unauthorized,
+        constraint = credit_record.is_active @ PrivateScoreError::CreditRecordInactive,
+        constraint = !credit_record.frozen @ PrivateScoreError::CreditRecordInactive
+    )]
+    pub credit_record: Account<'info, CreditRecord>,
+
+    #[account(
+        mut,
+        constraint = loan.borrower == borrower.key() @ PrivateScoreError::Unauthorized,
+        constraint = loan.pool == pool.key() @ PrivateScoreError::InvalidAccountState,
+        constraint = loan.status == LoanStatus::Active @ PrivateScoreError::LoanNotActive,
+        constraint = loan.is_credit_verified() @ PrivateScoreError::TierDoesNotQualify
+    )]
+    pub loan: Account<'info, Loan>,
+
+    #[account(
+        mut,
+        seeds = [b"collateral_vault", loan.key().as_ref()],
+        bump
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = borrower_collateral_account.owner == borrower.key() @ PrivateScoreError::InvalidCollateralAccount
+    )]
+    pub borrower_collateral_account: Account<'info, TokenAccount>,
+
+    /// Required once the pool has a proof replay cache; byte-identical proofs are rejected
+    #[account(
+        mut,
+        seeds = [ProofReplayCache::SEED_PREFIX, pool.key().as_ref()],
+        bump = proof_replay_cache.bump
+    )]
+    pub proof_replay_cache: Option<Account<'info, ProofReplayCache>>,
+
+    /// CHECK: Sunspot ZK verifier program; must be the one configured on the pool
+    #[account(
+        constraint = zk_verifier.key() == pool.verifier_program @ PrivateScoreError::VerifierError,
+        constraint = zk_verifier.executable @ PrivateScoreError::VerifierError
+    )]
+    pub zk_verifier: AccountInfo<'info>,
+
+    /// Required once the pool has a price oracle
+    pub price_oracle: Option<Account<'info, PriceFeed>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<ReleaseUpgradedCollateral>, proof: Vec<u8>, public_inputs: Vec<u8>) -> Result<()> {
+    let clock = Clock::get()?;
+    let borrow_index = ctx.accounts.pool.accrue_borrow_index(clock.unix_timestamp);
+    let pool = &ctx.accounts.pool;
+    let credit_record = &ctx.accounts.credit_record;
+
+    require!(credit_record.can_borrow(clock.unix_timestamp), PrivateScoreError::CreditExpired);
+    let proof_hash = verify_credit_proof(pool, &credit_record.commitment, &proof, &public_inputs)?;
+    pool.record_proof(ctx.accounts.proof_replay_cache.as_mut(), proof_hash, clock.unix_timestamp)?;
+
+    let new_ratio = pool.collateral_ratio_for_tier(credit_record.tier);
+    let loan = &mut ctx.accounts.loan;
+    require!(new_ratio < loan.collateral_ratio, PrivateScoreError::TierDoesNotQualify);
+
+    loan.accrue_interest(borrow_index, clock.unix_timestamp)?;
+    let old_ratio = loan.collateral_ratio;
+    loan.collateral_ratio = new_ratio;
+
+    // Keep the cosigner's pledge in place; only the borrower's excess is freed
+    let required = pool.required_collateral_for_value(
+        loan.required_collateral_value(),
+        ctx.accounts.price_oracle.as_ref(),
+        clock.unix_timestamp,
+        ctx.accounts.global_config.max_oracle_staleness,
+    )?;
+    let release = loan.total_collateral().saturating_sub(required).min(loan.collateral_locked);
+    require!(release > 0, PrivateScoreError::InvalidAmount);
+
+    loan.collateral_locked -= release;
+    loan.proof_hash = proof_hash;
+    loan.credit_commitment = credit_record.commitment;
+
+    let credit_record = &mut ctx.accounts.credit_record;
+    credit_record.proofs_verified = credit_record.proofs_verified.saturating_add(1);
+    credit_record.increment_nonce();
+
+    let loan_key = loan.key();
+    let seeds = &[b"collateral_vault".as_ref(), loan_key.as_ref(), &[ctx.bumps.collateral_vault]];
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.collateral_vault.to_account_info(),
+        to: ctx.accounts.borrower_collateral_account.to_account_info(),
+        authority: ctx.accounts.collateral_vault.to_account_info(),
+    };
+    token::transfer(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, &[seeds]),
+        release,
+    )?;
+
+    msg!("Loan {} ratio {}% -> {}% after tier upgrade", loan_key, old_ratio / 100, new_ratio / 100);
+    msg!("Collateral released: {}. Still locked: {}", release, loan.collateral_locked);
+    Ok(())
+}
@@ -0,0 +1,36 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! REMOVE ALT REPAY TOKEN - Stop accepting an alternate stablecoin for repayment
+//! ═══════════════════════════════════════════════════════════════════════════
+
+use anchor_lang::prelude::*;
+use crate::state::{AdminMultisig, AltRepayToken, LendingPool};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct RemoveAltRepayToken<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = pool.authority == authority.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [AltRepayToken::SEED_PREFIX, pool.key().as_ref(), alt_repay_token.mint.as_ref()],
+        bump = alt_repay_token.bump
+    )]
+    pub alt_repay_token: Account<'info, AltRepayToken>,
+
+    /// Required when the pool has an admin multisig; approvers sign via remaining_accounts
+    pub admin_multisig: Option<Account<'info, AdminMultisig>>,
+}
+
+pub fn handler(ctx: Context<RemoveAltRepayToken>) -> Result<()> {
+    ctx.accounts.pool.require_admin_approval(ctx.accounts.admin_multisig.as_ref(), ctx.remaining_accounts)?;
+
+    msg!("Pool {} no longer accepts {} for repayment", ctx.accounts.pool.pool_id, ctx.accounts.alt_repay_token.mint);
+    Ok(())
+}
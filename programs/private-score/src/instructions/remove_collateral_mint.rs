@@ -0,0 +1,40 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! REMOVE COLLATERAL MINT - Stop accepting a whitelisted mint for new loans
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! Existing loans keep their collateral; only new borrows are affected.
+
+use anchor_lang::prelude::*;
+use crate::state::{AdminMultisig, CollateralWhitelist, LendingPool};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct RemoveCollateralMint<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = pool.authority == authority.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        mut,
+        seeds = [CollateralWhitelist::SEED_PREFIX, pool.key().as_ref()],
+        bump = collateral_whitelist.bump
+    )]
+    pub collateral_whitelist: Account<'info, CollateralWhitelist>,
+
+    /// Required when the pool has an admin multisig; approvers sign via remaining_accounts
+    pub admin_multisig: Option<Account<'info, AdminMultisig>>,
+}
+
+pub fn handler(ctx: Context<RemoveCollateralMint>, mint: Pubkey) -> Result<()> {
+    ctx.accounts.pool.require_admin_approval(ctx.accounts.admin_multisig.as_ref(), ctx.remaining_accounts)?;
+
+    let whitelist = &mut ctx.accounts.collateral_whitelist;
+    whitelist.remove(&mint)?;
+    whitelist.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Collateral mint {} removed from pool {}", mint, ctx.accounts.pool.pool_id);
+    Ok(())
+}
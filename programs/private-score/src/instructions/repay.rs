@@ -1,10 +1,14 @@
 //! ═══════════════════════════════════════════════════════════════════════════
 //! REPAY - Loan repayment with interest calculation
 //! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! Only the borrower may repay, here and in the alt-token and collateral
+//! variants. There is no third-party repayment path, so `repayment_count` and
+//! the credit record's repayment history cannot be padded by outside payers.
 
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::{CreditRecord, LendingPool, Loan, LoanStatus};
+use crate::state::{CreditRecord, CreditTier, GlobalConfig, LendingPool, Loan, LoanStatus, PriceFeed, RepaymentMade};
 use crate::errors::PrivateScoreError;
 
 #[derive(Accounts)]
@@ -12,26 +16,35 @@ pub struct Repay<'info> {
     #[account(mut)]
     pub borrower: Signer<'info>,
 
+    #[account(
+        seeds = [GlobalConfig::SEED],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
     #[account(mut)]
     pub pool: Account<'info, LendingPool>,
 
     #[account(
         mut,
+        constraint = loan.pool == pool.key() @ PrivateScoreError::InvalidAccountState,
         constraint = loan.borrower == borrower.key() @ PrivateScoreError::Unauthorized,
         constraint = loan.status == LoanStatus::Active @ PrivateScoreError::LoanNotActive
     )]
     pub loan: Account<'info, Loan>,
 
+    /// Optional; when passed it must be the record the borrower's loans are attributed to
     #[account(
         mut,
-        seeds = [b"credit", borrower.key().as_ref()],
-        bump = credit_record.bump
+        seeds = [b"credit", credit_record.owner.as_ref()],
+        bump = credit_record.bump,
+        constraint = credit_record.borrower_key() == borrower.key() @ PrivateScoreError::Unauthorized
     )]
     pub credit_record: Option<Account<'info, CreditRecord>>,
 
     #[account(
         mut,
-        constraint = borrower_token_account.mint == pool.token_mint @ PrivateScoreError::InvalidTokenMint
+        constraint = borrower_token_account.mint == pool.loan_mint @ PrivateScoreError::InvalidTokenMint
     )]
     pub borrower_token_account: Account<'info, TokenAccount>,
 
@@ -47,20 +60,93 @@ pub struct Repay<'info> {
     #[account(mut)]
     pub borrower_collateral_account: Account<'info, TokenAccount>,
 
+    /// Required for cosigned loans; the pledge is returned on full repayment
+    #[account(
+        mut,
+        seeds = [b"cosigner_vault", loan.key().as_ref()],
+        bump
+    )]
+    pub cosigner_vault: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = cosigner_collateral_account.owner == loan.cosigner @ PrivateScoreError::InvalidCollateralAccount
+    )]
+    pub cosigner_collateral_account: Option<Account<'info, TokenAccount>>,
+
+    /// Required once the pool has a price oracle
+    pub price_oracle: Option<Account<'info, PriceFeed>>,
+
     pub token_program: Program<'info, Token>,
 }
 
 pub fn handler(ctx: Context<Repay>, amount: u64) -> Result<()> {
     let clock = Clock::get()?;
+    let borrow_index = ctx.accounts.pool.accrue_borrow_index(clock.unix_timestamp);
     let loan = &mut ctx.accounts.loan;
+    let loan_key = loan.key();
 
     // Accrue interest first
-    loan.accrue_interest(clock.unix_timestamp);
+    loan.accrue_interest(borrow_index, clock.unix_timestamp)?;
 
     let total_debt = loan.total_debt();
     require!(amount > 0, PrivateScoreError::InvalidAmount);
     require!(amount <= total_debt, PrivateScoreError::RepaymentExceedsDebt);
 
+    // ═══════════════════════════════════════════════════════════════════════
+    // EFFECTS - all account state is settled before any token moves, so a
+    // failing CPI below reverts the instruction with nothing half-applied
+    // ═══════════════════════════════════════════════════════════════════════
+    loan.apply_repayment(amount);
+    loan.repayment_count = loan.repayment_count.saturating_add(1);
+    loan.refresh_installments(clock.unix_timestamp);
+
+    // A sub-threshold remainder is forgiven rather than left blocking the collateral
+    let is_fully_repaid = loan.total_debt() == 0 || ctx.accounts.pool.is_dust(loan.total_debt());
+    let is_on_time = !loan.is_overdue(clock.unix_timestamp);
+    let tier = ctx.accounts.credit_record.as_ref().map_or(CreditTier::Unknown, |record| record.tier);
+
+    let (collateral_release, cosigner_release, rebate) = if is_fully_repaid {
+        loan.status = LoanStatus::Repaid;
+        loan.closed_at = clock.unix_timestamp;
+        loan.repaid_on_time = is_on_time;
+
+        // Interest is settled gross; the on-time rebate is then paid from protocol
+        // reserves, capped at their balance, so lenders and insurance are untouched
+        let pool = &mut ctx.accounts.pool;
+        pool.release_loan(loan.principal, loan.interest_rate)?;
+        pool.settle_interest(loan.interest_accrued);
+        let rebate = if is_on_time {
+            pool.on_time_rebate(loan.interest_accrued, tier).min(pool.protocol_reserves)
+        } else {
+            0
+        };
+        pool.protocol_reserves -= rebate;
+
+        // Update credit record if exists
+        if let Some(credit_record) = &mut ctx.accounts.credit_record {
+            credit_record.record_repayment(loan.principal, is_on_time);
+        }
+
+        (loan.collateral_locked, loan.cosigner_collateral, rebate)
+    } else {
+        // Release surplus collateral; the rest stays at the loan's origination ratio.
+        // A cosigner's pledge counts toward the requirement but is only returned in full.
+        let required = ctx.accounts.pool.required_collateral_for_value(
+            loan.required_collateral_value(),
+            ctx.accounts.price_oracle.as_ref(),
+            clock.unix_timestamp,
+            ctx.accounts.global_config.max_oracle_staleness,
+        )?;
+        let release = loan.total_collateral().saturating_sub(required).min(loan.collateral_locked);
+        loan.collateral_locked = loan.collateral_locked.saturating_sub(release);
+        (release, 0, 0)
+    };
+    ctx.accounts.pool.updated_at = clock.unix_timestamp;
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // INTERACTIONS
+    // ═══════════════════════════════════════════════════════════════════════
     // Transfer repayment to vault
     let cpi_accounts = Transfer {
         from: ctx.accounts.borrower_token_account.to_account_info(),
@@ -72,20 +158,24 @@ pub fn handler(ctx: Context<Repay>, amount: u64) -> Result<()> {
         amount,
     )?;
 
-    // Update loan state
-    loan.amount_repaid = loan.amount_repaid.saturating_add(amount);
-    loan.repayment_count = loan.repayment_count.saturating_add(1);
-
-    let is_fully_repaid = loan.total_debt() == 0;
-    let is_on_time = !loan.is_overdue(clock.unix_timestamp);
+    if rebate > 0 {
+        let pool = &ctx.accounts.pool;
+        let pool_id_bytes = pool.pool_id.to_le_bytes();
+        let seeds = &[b"pool".as_ref(), pool_id_bytes.as_ref(), &[pool.bump]];
 
-    if is_fully_repaid {
-        loan.status = LoanStatus::Repaid;
-        loan.closed_at = clock.unix_timestamp;
-        loan.repaid_on_time = is_on_time;
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.vault.to_account_info(),
+            to: ctx.accounts.borrower_token_account.to_account_info(),
+            authority: ctx.accounts.pool.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, &[seeds]),
+            rebate,
+        )?;
+    }
 
-        // Return collateral
-        let loan_key = ctx.accounts.loan.key();
+    // Return collateral
+    if collateral_release > 0 {
         let seeds = &[b"collateral_vault".as_ref(), loan_key.as_ref(), &[ctx.bumps.collateral_vault]];
 
         let cpi_accounts = Transfer {
@@ -95,25 +185,65 @@ pub fn handler(ctx: Context<Repay>, amount: u64) -> Result<()> {
         };
         token::transfer(
             CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, &[seeds]),
-            loan.collateral_locked,
+            collateral_release,
         )?;
+    }
 
-        // Update pool
-        let pool = &mut ctx.accounts.pool;
-        pool.total_borrowed = pool.total_borrowed.saturating_sub(loan.principal);
-        pool.active_loans = pool.active_loans.saturating_sub(1);
-        pool.total_interest_accrued = pool.total_interest_accrued.saturating_add(loan.interest_accrued);
+    if cosigner_release > 0 {
+        let (Some(cosigner_vault), Some(cosigner_account)) =
+            (&ctx.accounts.cosigner_vault, &ctx.accounts.cosigner_collateral_account)
+        else {
+            return err!(PrivateScoreError::InvalidCollateralAccount);
+        };
+        let seeds = &[b"cosigner_vault".as_ref(), loan_key.as_ref(), &[ctx.bumps.cosigner_vault]];
 
-        // Update credit record if exists
-        if let Some(credit_record) = &mut ctx.accounts.credit_record {
-            credit_record.record_repayment(loan.principal, is_on_time);
-        }
+        let cpi_accounts = Transfer {
+            from: cosigner_vault.to_account_info(),
+            to: cosigner_account.to_account_info(),
+            authority: cosigner_vault.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, &[seeds]),
+            cosigner_release,
+        )?;
+    }
 
-        msg!("Loan fully repaid! Collateral returned: {}", loan.collateral_locked);
+    let loan = &ctx.accounts.loan;
+    if is_fully_repaid {
+        msg!("Loan fully repaid! Collateral returned: {}", collateral_release);
+        if loan.total_debt() > 0 {
+            msg!("Dust forgiven: {}", loan.total_debt());
+        }
+        if rebate > 0 {
+            msg!("On-time rebate: {}", rebate);
+        }
     } else {
         msg!("Partial repayment: {}. Remaining debt: {}", amount, loan.total_debt());
+        msg!("Collateral released: {}. Still locked: {}", collateral_release, loan.collateral_locked);
     }
 
-    ctx.accounts.pool.updated_at = clock.unix_timestamp;
+    // Report post-repayment health, valued through the oracle as in liquidate
+    ctx.accounts.collateral_vault.reload()?;
+    let mut collateral_amount = ctx.accounts.collateral_vault.amount;
+    if let Some(cosigner_vault) = &mut ctx.accounts.cosigner_vault {
+        cosigner_vault.reload()?;
+        collateral_amount = collateral_amount.saturating_add(cosigner_vault.amount);
+    }
+    let remaining_debt = if is_fully_repaid { 0 } else { loan.total_debt() };
+    let collateral_value = ctx.accounts.pool.collateral_value(
+        collateral_amount,
+        ctx.accounts.price_oracle.as_ref(),
+        clock.unix_timestamp,
+        ctx.accounts.global_config.max_oracle_staleness,
+    )?;
+    let health_factor = loan.health_factor(collateral_value, remaining_debt);
+    emit!(RepaymentMade {
+        loan: loan_key,
+        amount,
+        remaining_debt,
+        health_factor,
+        timestamp: clock.unix_timestamp,
+    });
+
     Ok(())
-}
\ No newline at end of file
+}
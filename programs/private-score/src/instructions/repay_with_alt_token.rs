@@ -0,0 +1,184 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! REPAY WITH ALT TOKEN - Repay in a whitelisted alternate stablecoin
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! The borrower's alternate tokens go through the pool's configured adapter,
+//! whose loan-mint output lands directly in the pool vault. As with
+//! `repay_with_collateral`, both balances are measured around the CPI and only
+//! the vault increase is credited against the debt.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{AltRepayToken, CreditRecord, LendingPool, Loan, LoanStatus, RepaymentMade};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct RepayWithAltToken<'info> {
+    pub borrower: Signer<'info>,
+
+    #[account(mut)]
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        mut,
+        constraint = loan.borrower == borrower.key() @ PrivateScoreError::Unauthorized,
+        constraint = loan.pool == pool.key() @ PrivateScoreError::InvalidAccountState,
+        constraint = loan.status == LoanStatus::Active @ PrivateScoreError::LoanNotActive,
+        // A cosigner's pledge is only returned through `repay`
+        constraint = !loan.has_cosigner() @ PrivateScoreError::InvalidAccountState
+    )]
+    pub loan: Account<'info, Loan>,
+
+    #[account(
+        mut,
+        seeds = [b"credit", credit_record.owner.as_ref()],
+        bump = credit_record.bump,
+        constraint = credit_record.borrower_key() == borrower.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub credit_record: Option<Account<'info, CreditRecord>>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == pool.vault @ PrivateScoreError::InvalidVault
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// Only mints the pool has whitelisted have an AltRepayToken at this address
+    #[account(
+        seeds = [AltRepayToken::SEED_PREFIX, pool.key().as_ref(), borrower_alt_account.mint.as_ref()],
+        bump = alt_repay_token.bump
+    )]
+    pub alt_repay_token: Account<'info, AltRepayToken>,
+
+    #[account(
+        mut,
+        constraint = borrower_alt_account.owner == borrower.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub borrower_alt_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"collateral_vault", loan.key().as_ref()],
+        bump
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = borrower_collateral_account.owner == borrower.key() @ PrivateScoreError::InvalidCollateralAccount
+    )]
+    pub borrower_collateral_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Must be the adapter configured for this alternate token
+    #[account(
+        constraint = adapter_program.key() == alt_repay_token.adapter_program @ PrivateScoreError::Unauthorized
+    )]
+    pub adapter_program: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Adapter accounts are passed as remaining_accounts
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RepayWithAltToken<'info>>,
+    max_amount_in: u64,
+    min_amount_out: u64,
+    adapter_data: Vec<u8>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(max_amount_in > 0, PrivateScoreError::InvalidAmount);
+    require!(min_amount_out > 0, PrivateScoreError::InvalidAmount);
+
+    let borrow_index = ctx.accounts.pool.accrue_borrow_index(clock.unix_timestamp);
+    ctx.accounts.loan.accrue_interest(borrow_index, clock.unix_timestamp)?;
+    let total_debt = ctx.accounts.loan.total_debt();
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // CONVERT ALT TOKEN -> LOAN MINT
+    // ═══════════════════════════════════════════════════════════════════════
+    let alt_before = ctx.accounts.borrower_alt_account.amount;
+    let vault_before = ctx.accounts.vault.amount;
+
+    let accounts = ctx.remaining_accounts
+        .iter()
+        .map(|account| AccountMeta {
+            pubkey: account.key(),
+            is_signer: account.is_signer,
+            is_writable: account.is_writable,
+        })
+        .collect();
+    let adapter_ix = Instruction {
+        program_id: ctx.accounts.adapter_program.key(),
+        accounts,
+        data: adapter_data,
+    };
+    invoke(&adapter_ix, ctx.remaining_accounts)?;
+
+    ctx.accounts.borrower_alt_account.reload()?;
+    ctx.accounts.vault.reload()?;
+    let amount_in = alt_before.saturating_sub(ctx.accounts.borrower_alt_account.amount);
+    let amount_out = ctx.accounts.vault.amount.saturating_sub(vault_before);
+
+    require!(amount_in <= max_amount_in, PrivateScoreError::SlippageExceeded);
+    require!(amount_out >= min_amount_out, PrivateScoreError::SlippageExceeded);
+    require!(amount_out <= total_debt, PrivateScoreError::RepaymentExceedsDebt);
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // APPLY TO DEBT
+    // ═══════════════════════════════════════════════════════════════════════
+    let loan_key = ctx.accounts.loan.key();
+    let loan = &mut ctx.accounts.loan;
+    loan.apply_repayment(amount_out);
+    loan.repayment_count = loan.repayment_count.saturating_add(1);
+    loan.refresh_installments(clock.unix_timestamp);
+
+    if loan.total_debt() == 0 {
+        let is_on_time = !loan.is_overdue(clock.unix_timestamp);
+        loan.status = LoanStatus::Repaid;
+        loan.closed_at = clock.unix_timestamp;
+        loan.repaid_on_time = is_on_time;
+
+        let collateral = ctx.accounts.collateral_vault.amount;
+        if collateral > 0 {
+            let seeds = &[b"collateral_vault".as_ref(), loan_key.as_ref(), &[ctx.bumps.collateral_vault]];
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.collateral_vault.to_account_info(),
+                to: ctx.accounts.borrower_collateral_account.to_account_info(),
+                authority: ctx.accounts.collateral_vault.to_account_info(),
+            };
+            token::transfer(
+                CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, &[seeds]),
+                collateral,
+            )?;
+        }
+
+        let pool = &mut ctx.accounts.pool;
+        pool.release_loan(loan.principal, loan.interest_rate)?;
+        pool.settle_interest(loan.interest_accrued);
+
+        if let Some(credit_record) = &mut ctx.accounts.credit_record {
+            credit_record.record_repayment(loan.principal, is_on_time);
+        }
+
+        msg!("Loan fully repaid in alternate token. Collateral returned: {}", collateral);
+    } else {
+        msg!("Partial repayment in alternate token: {}. Remaining debt: {}", amount_out, loan.total_debt());
+    }
+    msg!("Converted {} {} -> {} loan tokens", amount_in, ctx.accounts.alt_repay_token.mint, amount_out);
+
+    ctx.accounts.collateral_vault.reload()?;
+    let remaining_debt = loan.total_debt();
+    let collateral_value = ctx.accounts.pool.to_loan_units(ctx.accounts.collateral_vault.amount);
+    emit!(RepaymentMade {
+        loan: loan_key,
+        amount: amount_out,
+        remaining_debt,
+        health_factor: loan.health_factor(collateral_value, remaining_debt),
+        timestamp: clock.unix_timestamp,
+    });
+
+    ctx.accounts.pool.updated_at = clock.unix_timestamp;
+    Ok(())
+}
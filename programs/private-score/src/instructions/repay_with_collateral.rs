@@ -0,0 +1,205 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! REPAY WITH COLLATERAL - Swap locked collateral to the loan mint and repay
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! The collateral vault PDA signs a CPI into a whitelisted DEX (Jupiter, Orca)
+//! whose output lands directly in the pool vault. The program never trusts the
+//! route's own accounting: it measures both vault balances around the swap and
+//! enforces `min_amount_out` and `max_collateral_in` itself. Since the borrower
+//! picks the route and the price, any debt left afterwards must still be backed
+//! at the loan's collateral ratio.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{CreditRecord, GlobalConfig, LendingPool, Loan, LoanStatus, PriceFeed, RepaymentMade};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct RepayWithCollateral<'info> {
+    pub borrower: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalConfig::SEED],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        mut,
+        constraint = loan.borrower == borrower.key() @ PrivateScoreError::Unauthorized,
+        constraint = loan.pool == pool.key() @ PrivateScoreError::InvalidAccountState,
+        constraint = loan.status == LoanStatus::Active @ PrivateScoreError::LoanNotActive,
+        // A cosigner's pledge is only returned through `repay`
+        constraint = !loan.has_cosigner() @ PrivateScoreError::InvalidAccountState
+    )]
+    pub loan: Account<'info, Loan>,
+
+    #[account(
+        mut,
+        seeds = [b"credit", credit_record.owner.as_ref()],
+        bump = credit_record.bump,
+        constraint = credit_record.borrower_key() == borrower.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub credit_record: Option<Account<'info, CreditRecord>>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == pool.vault @ PrivateScoreError::InvalidVault
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"collateral_vault", loan.key().as_ref()],
+        bump
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = borrower_collateral_account.owner == borrower.key() @ PrivateScoreError::InvalidCollateralAccount
+    )]
+    pub borrower_collateral_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Must be one of the DEX programs whitelisted in the global config
+    #[account(
+        constraint = global_config.is_swap_program(&swap_program.key()) @ PrivateScoreError::Unauthorized
+    )]
+    pub swap_program: UncheckedAccount<'info>,
+
+    /// Required once the pool has a price oracle
+    pub price_oracle: Option<Account<'info, PriceFeed>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Route accounts for the DEX are passed as remaining_accounts
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RepayWithCollateral<'info>>,
+    max_collateral_in: u64,
+    min_amount_out: u64,
+    swap_data: Vec<u8>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(max_collateral_in > 0, PrivateScoreError::InvalidAmount);
+    require!(min_amount_out > 0, PrivateScoreError::InvalidAmount);
+    require!(max_collateral_in <= ctx.accounts.loan.collateral_locked, PrivateScoreError::InsufficientCollateral);
+
+    let borrow_index = ctx.accounts.pool.accrue_borrow_index(clock.unix_timestamp);
+    ctx.accounts.loan.accrue_interest(borrow_index, clock.unix_timestamp)?;
+    let total_debt = ctx.accounts.loan.total_debt();
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // SWAP COLLATERAL -> LOAN MINT
+    // ═══════════════════════════════════════════════════════════════════════
+    let collateral_before = ctx.accounts.collateral_vault.amount;
+    let vault_before = ctx.accounts.vault.amount;
+
+    let collateral_vault_key = ctx.accounts.collateral_vault.key();
+    let accounts = ctx.remaining_accounts
+        .iter()
+        .map(|account| AccountMeta {
+            pubkey: account.key(),
+            is_signer: account.is_signer || account.key() == collateral_vault_key,
+            is_writable: account.is_writable,
+        })
+        .collect();
+    let swap_ix = Instruction {
+        program_id: ctx.accounts.swap_program.key(),
+        accounts,
+        data: swap_data,
+    };
+
+    let loan_key = ctx.accounts.loan.key();
+    let seeds = &[b"collateral_vault".as_ref(), loan_key.as_ref(), &[ctx.bumps.collateral_vault]];
+    invoke_signed(&swap_ix, ctx.remaining_accounts, &[seeds])?;
+
+    ctx.accounts.collateral_vault.reload()?;
+    ctx.accounts.vault.reload()?;
+    let collateral_spent = collateral_before.saturating_sub(ctx.accounts.collateral_vault.amount);
+    let amount_out = ctx.accounts.vault.amount.saturating_sub(vault_before);
+
+    require!(collateral_spent <= max_collateral_in, PrivateScoreError::SlippageExceeded);
+    require!(amount_out >= min_amount_out, PrivateScoreError::SlippageExceeded);
+    require!(amount_out <= total_debt, PrivateScoreError::RepaymentExceedsDebt);
+
+    // A bad fill must not leave the remaining debt under-collateralized
+    let remaining_debt = total_debt - amount_out;
+    let collateral_value = ctx.accounts.pool.collateral_value(
+        ctx.accounts.collateral_vault.amount,
+        ctx.accounts.price_oracle.as_ref(),
+        clock.unix_timestamp,
+        ctx.accounts.global_config.max_oracle_staleness,
+    )?;
+    require!(
+        collateral_value as u128 * 10000 >= remaining_debt as u128 * ctx.accounts.loan.collateral_ratio as u128,
+        PrivateScoreError::HealthFactorTooLow
+    );
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // APPLY TO DEBT
+    // ═══════════════════════════════════════════════════════════════════════
+    let loan = &mut ctx.accounts.loan;
+    loan.collateral_locked = loan.collateral_locked.saturating_sub(collateral_spent);
+    loan.apply_repayment(amount_out);
+    loan.repayment_count = loan.repayment_count.saturating_add(1);
+    loan.refresh_installments(clock.unix_timestamp);
+
+    if loan.total_debt() == 0 {
+        let is_on_time = !loan.is_overdue(clock.unix_timestamp);
+        loan.status = LoanStatus::Repaid;
+        loan.closed_at = clock.unix_timestamp;
+        loan.repaid_on_time = is_on_time;
+
+        // Return whatever collateral the swap did not consume
+        let remaining_collateral = ctx.accounts.collateral_vault.amount;
+        if remaining_collateral > 0 {
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.collateral_vault.to_account_info(),
+                to: ctx.accounts.borrower_collateral_account.to_account_info(),
+                authority: ctx.accounts.collateral_vault.to_account_info(),
+            };
+            token::transfer(
+                CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, &[seeds]),
+                remaining_collateral,
+            )?;
+        }
+
+        let pool = &mut ctx.accounts.pool;
+        pool.release_loan(loan.principal, loan.interest_rate)?;
+        pool.settle_interest(loan.interest_accrued);
+
+        if let Some(credit_record) = &mut ctx.accounts.credit_record {
+            credit_record.record_repayment(loan.principal, is_on_time);
+        }
+
+        msg!("Loan fully repaid from collateral. Returned: {}", remaining_collateral);
+    } else {
+        msg!("Partial repayment from collateral: {}. Remaining debt: {}", amount_out, loan.total_debt());
+    }
+    msg!("Collateral swapped: {} -> {} loan tokens", collateral_spent, amount_out);
+
+    ctx.accounts.collateral_vault.reload()?;
+    let remaining_debt = loan.total_debt();
+    let collateral_value = ctx.accounts.pool.collateral_value(
+        ctx.accounts.collateral_vault.amount,
+        ctx.accounts.price_oracle.as_ref(),
+        clock.unix_timestamp,
+        ctx.accounts.global_config.max_oracle_staleness,
+    )?;
+    emit!(RepaymentMade {
+        loan: loan_key,
+        amount: amount_out,
+        remaining_debt,
+        health_factor: loan.health_factor(collateral_value, remaining_debt),
+        timestamp: clock.unix_timestamp,
+    });
+
+    ctx.accounts.pool.updated_at = clock.unix_timestamp;
+    Ok(())
+}
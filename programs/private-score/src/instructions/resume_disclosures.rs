@@ -0,0 +1,30 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! RESUME DISCLOSURES - Lift a disclosure pause; existing keys work again
+//! ═══════════════════════════════════════════════════════════════════════════
+
+use anchor_lang::prelude::*;
+use crate::state::CreditRecord;
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct ResumeDisclosures<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"credit", owner.key().as_ref()],
+        bump = credit_record.bump,
+        constraint = credit_record.owner == owner.key() @ PrivateScoreError::Unauthorized,
+        constraint = credit_record.disclosure_paused @ PrivateScoreError::InvalidAccountState
+    )]
+    pub credit_record: Account<'info, CreditRecord>,
+}
+
+pub fn handler(ctx: Context<ResumeDisclosures>) -> Result<()> {
+    let credit_record = &mut ctx.accounts.credit_record;
+    credit_record.disclosure_paused = false;
+    credit_record.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Disclosures for {} paused: {}", credit_record.owner, false);
+    Ok(())
+}
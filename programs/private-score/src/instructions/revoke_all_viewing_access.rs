@@ -0,0 +1,57 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! REVOKE ALL VIEWING ACCESS - Revoke every viewing key passed in one call
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! Viewing keys are supplied via `remaining_accounts` (writable). Keys that are
+//! already inactive are skipped; keys belonging to another owner or record fail.
+
+use anchor_lang::prelude::*;
+use crate::state::{CreditRecord, ViewingKey, ViewingKeyStatus};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct RevokeAllViewingAccess<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"credit", owner.key().as_ref()],
+        bump = credit_record.bump,
+        constraint = credit_record.owner == owner.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub credit_record: Account<'info, CreditRecord>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, RevokeAllViewingAccess<'info>>) -> Result<()> {
+    let owner = ctx.accounts.owner.key();
+    let credit_record_key = ctx.accounts.credit_record.key();
+    let mut revoked: u32 = 0;
+
+    for account_info in ctx.remaining_accounts.iter() {
+        require!(account_info.is_writable, PrivateScoreError::InvalidViewingKey);
+
+        let mut viewing_key: Account<'info, ViewingKey> = Account::try_from(account_info)?;
+        require!(viewing_key.owner == owner, PrivateScoreError::Unauthorized);
+        require!(viewing_key.credit_record == credit_record_key, PrivateScoreError::InvalidViewingKey);
+
+        if viewing_key.status != ViewingKeyStatus::Active {
+            continue;
+        }
+
+        viewing_key.revoke();
+        viewing_key.exit(ctx.program_id)?;
+        revoked = revoked.saturating_add(1);
+    }
+
+    let credit_record = &mut ctx.accounts.credit_record;
+    credit_record.active_viewing_keys = credit_record.active_viewing_keys.saturating_sub(revoked);
+    if credit_record.active_viewing_keys == 0 {
+        credit_record.disclosure_enabled = false;
+    }
+
+    msg!("Revoked {} viewing keys for {}", revoked, owner);
+    msg!("Active viewing keys remaining: {}", credit_record.active_viewing_keys);
+
+    Ok(())
+}
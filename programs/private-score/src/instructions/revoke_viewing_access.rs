@@ -3,7 +3,7 @@
 //! ═══════════════════════════════════════════════════════════════════════════
 
 use anchor_lang::prelude::*;
-use crate::state::{ViewingKey, ViewingKeyStatus};
+use crate::state::{CreditRecord, ViewingKey, ViewingKeyStatus};
 use crate::errors::PrivateScoreError;
 
 #[derive(Accounts)]
@@ -11,9 +11,18 @@ pub struct RevokeViewingAccess<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
 
+    #[account(
+        mut,
+        seeds = [b"credit", owner.key().as_ref()],
+        bump = credit_record.bump,
+        constraint = credit_record.owner == owner.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub credit_record: Account<'info, CreditRecord>,
+
     #[account(
         mut,
         constraint = viewing_key.owner == owner.key() @ PrivateScoreError::Unauthorized,
+        constraint = viewing_key.credit_record == credit_record.key() @ PrivateScoreError::InvalidViewingKey,
         constraint = viewing_key.status == ViewingKeyStatus::Active @ PrivateScoreError::ViewingKeyNotActive
     )]
     pub viewing_key: Account<'info, ViewingKey>,
@@ -24,6 +33,13 @@ pub fn handler(ctx: Context<RevokeViewingAccess>) -> Result<()> {
     let viewer = viewing_key.viewer;  
     viewing_key.revoke();
 
+    // Stop flagging the record as disclosing once the last key is gone
+    let credit_record = &mut ctx.accounts.credit_record;
+    credit_record.active_viewing_keys = credit_record.active_viewing_keys.saturating_sub(1);
+    if credit_record.active_viewing_keys == 0 {
+        credit_record.disclosure_enabled = false;
+    }
+
     msg!("Viewing access revoked for {}", viewer);
     msg!("Total accesses before revocation: {}", viewing_key.access_count);
 
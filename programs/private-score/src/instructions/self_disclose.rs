@@ -0,0 +1,43 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! SELF DISCLOSE - Owner attests their own tier and history on-chain
+//! ═══════════════════════════════════════════════════════════════════════════
+
+use anchor_lang::prelude::*;
+use crate::state::{CreditRecord, DisclosureResponse};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct SelfDisclose<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"credit", owner.key().as_ref()],
+        bump = credit_record.bump,
+        constraint = credit_record.owner == owner.key() @ PrivateScoreError::Unauthorized,
+        constraint = credit_record.is_active @ PrivateScoreError::CreditRecordInactive,
+        constraint = !credit_record.frozen @ PrivateScoreError::CreditRecordInactive
+    )]
+    pub credit_record: Account<'info, CreditRecord>,
+}
+
+pub fn handler(ctx: Context<SelfDisclose>) -> Result<()> {
+    let clock = Clock::get()?;
+    let credit_record = &ctx.accounts.credit_record;
+
+    // No viewing key needed - the owner's signature is the attestation
+    emit!(DisclosureResponse {
+        credit_record: credit_record.key(),
+        viewer: ctx.accounts.owner.key(),
+        disclosed_at: clock.unix_timestamp,
+        tier: credit_record.tier,
+        loans_taken: credit_record.loans_taken,
+        on_time_repayments: credit_record.on_time_repayments,
+        late_repayments: credit_record.late_repayments,
+        tier_disclosed: true,
+        history_disclosed: true,
+        full_access_granted: true,
+    });
+
+    msg!("Credit record self-disclosed by {}", ctx.accounts.owner.key());
+    Ok(())
+}
@@ -0,0 +1,34 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! SET AUTO RENEW - Keep a credit record alive while it is in regular use
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! With auto-renewal on, every verify_and_borrow that checks a fresh proof
+//! moves `expires_at` to at least the default duration from now. Session
+//! borrows reuse an earlier proof and do not renew.
+
+use anchor_lang::prelude::*;
+use crate::state::CreditRecord;
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct SetAutoRenew<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"credit", owner.key().as_ref()],
+        bump = credit_record.bump,
+        constraint = credit_record.owner == owner.key() @ PrivateScoreError::Unauthorized,
+        constraint = !credit_record.frozen @ PrivateScoreError::CreditRecordInactive
+    )]
+    pub credit_record: Account<'info, CreditRecord>,
+}
+
+pub fn handler(ctx: Context<SetAutoRenew>, enabled: bool) -> Result<()> {
+    let credit_record = &mut ctx.accounts.credit_record;
+    credit_record.auto_renew = enabled;
+    credit_record.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Auto-renewal for {}: {}", credit_record.owner, enabled);
+    Ok(())
+}
@@ -0,0 +1,35 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! SET BENEFICIARY - Separate whose credit is measured from who manages it
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! For custodial setups: the owner keeps control of the record, viewing keys
+//! and disclosures, while credit loans are attributed to the beneficiary.
+//! Pass the default pubkey to attribute loans to the owner again. Existing
+//! loans keep their original borrower.
+
+use anchor_lang::prelude::*;
+use crate::state::CreditRecord;
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct SetBeneficiary<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"credit", owner.key().as_ref()],
+        bump = credit_record.bump,
+        constraint = credit_record.owner == owner.key() @ PrivateScoreError::Unauthorized,
+        constraint = !credit_record.frozen @ PrivateScoreError::CreditRecordInactive
+    )]
+    pub credit_record: Account<'info, CreditRecord>,
+}
+
+pub fn handler(ctx: Context<SetBeneficiary>, beneficiary: Pubkey) -> Result<()> {
+    let credit_record = &mut ctx.accounts.credit_record;
+    credit_record.beneficiary = beneficiary;
+    credit_record.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Beneficiary for {} set to {}", credit_record.owner, credit_record.borrower_key());
+    Ok(())
+}
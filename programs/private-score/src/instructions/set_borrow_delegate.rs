@@ -0,0 +1,36 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! SET BORROW DELEGATE - Let a smart wallet or bot borrow for the record owner
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! The delegate may sign verify_and_borrow; loans stay attributed to the owner
+//! and borrowed funds go to the owner's token account. Pass the default
+//! pubkey to remove the delegate.
+
+use anchor_lang::prelude::*;
+use crate::state::CreditRecord;
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct SetBorrowDelegate<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"credit", owner.key().as_ref()],
+        bump = credit_record.bump,
+        constraint = credit_record.owner == owner.key() @ PrivateScoreError::Unauthorized,
+        constraint = !credit_record.frozen @ PrivateScoreError::CreditRecordInactive
+    )]
+    pub credit_record: Account<'info, CreditRecord>,
+}
+
+pub fn handler(ctx: Context<SetBorrowDelegate>, borrow_delegate: Pubkey) -> Result<()> {
+    require!(borrow_delegate != ctx.accounts.owner.key(), PrivateScoreError::InvalidAccountState);
+
+    let credit_record = &mut ctx.accounts.credit_record;
+    credit_record.borrow_delegate = borrow_delegate;
+    credit_record.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Borrow delegate for {} set to {}", credit_record.owner, borrow_delegate);
+    Ok(())
+}
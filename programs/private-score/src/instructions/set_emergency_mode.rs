@@ -0,0 +1,32 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! SET EMERGENCY MODE - Open or close the principal-only lender exit
+//! ═══════════════════════════════════════════════════════════════════════════
+
+use anchor_lang::prelude::*;
+use crate::state::{AdminMultisig, LendingPool};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct SetEmergencyMode<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub pool: Account<'info, LendingPool>,
+
+    /// Required when the pool has an admin multisig; approvers sign via remaining_accounts
+    pub admin_multisig: Option<Account<'info, AdminMultisig>>,
+}
+
+pub fn handler(ctx: Context<SetEmergencyMode>, enabled: bool) -> Result<()> {
+    ctx.accounts.pool.require_admin_approval(ctx.accounts.admin_multisig.as_ref(), ctx.remaining_accounts)?;
+
+    let pool = &mut ctx.accounts.pool;
+    pool.emergency_mode = enabled;
+    pool.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Pool {} emergency mode: {}", pool.pool_id, enabled);
+    Ok(())
+}
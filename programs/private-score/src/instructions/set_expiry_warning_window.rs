@@ -0,0 +1,43 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! SET EXPIRY WARNING WINDOW - How early a viewing key warns before expiring
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! Disclosures inside the window emit AccessExpiringSoon so the owner can
+//! renew recurring access in time. A zero window turns the warning off.
+
+use anchor_lang::prelude::*;
+use crate::state::{CreditRecord, ViewingKey, ViewingKeyStatus};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct SetExpiryWarningWindow<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"credit", owner.key().as_ref()],
+        bump = credit_record.bump,
+        constraint = credit_record.owner == owner.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub credit_record: Account<'info, CreditRecord>,
+
+    #[account(
+        mut,
+        constraint = viewing_key.owner == owner.key() @ PrivateScoreError::Unauthorized,
+        constraint = viewing_key.credit_record == credit_record.key() @ PrivateScoreError::InvalidViewingKey,
+        constraint = viewing_key.status == ViewingKeyStatus::Active @ PrivateScoreError::ViewingKeyNotActive
+    )]
+    pub viewing_key: Account<'info, ViewingKey>,
+}
+
+pub fn handler(ctx: Context<SetExpiryWarningWindow>, expiry_warning_window: i64) -> Result<()> {
+    require!(
+        expiry_warning_window >= 0 && expiry_warning_window <= ViewingKey::MAX_EXPIRY,
+        PrivateScoreError::InvalidExpiry
+    );
+
+    let viewing_key = &mut ctx.accounts.viewing_key;
+    viewing_key.expiry_warning_window = expiry_warning_window;
+
+    msg!("Expiry warning for {} set to {}s before expiry", viewing_key.viewer, expiry_warning_window);
+    Ok(())
+}
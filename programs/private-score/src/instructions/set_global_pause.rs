@@ -0,0 +1,32 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! SET GLOBAL PAUSE - Halt or resume borrowing across every pool
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! Independent of each pool's `is_active`; repayments, liquidations and
+//! withdrawals keep working while paused.
+
+use anchor_lang::prelude::*;
+use crate::state::GlobalConfig;
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct SetGlobalPause<'info> {
+    pub super_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GlobalConfig::SEED],
+        bump = global_config.bump,
+        constraint = global_config.super_admin == super_admin.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+pub fn handler(ctx: Context<SetGlobalPause>, paused: bool) -> Result<()> {
+    let config = &mut ctx.accounts.global_config;
+    config.global_paused = paused;
+    config.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Protocol-wide borrow pause: {}", paused);
+    Ok(())
+}
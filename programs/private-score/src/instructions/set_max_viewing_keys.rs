@@ -0,0 +1,37 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! SET MAX VIEWING KEYS - Owner-configured cap on concurrent viewing keys
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! Lowering the cap below the current count does not revoke anything; it only
+//! blocks new grants until enough keys are revoked.
+
+use anchor_lang::prelude::*;
+use crate::state::CreditRecord;
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct SetMaxViewingKeys<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"credit", owner.key().as_ref()],
+        bump = credit_record.bump,
+        constraint = credit_record.owner == owner.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub credit_record: Account<'info, CreditRecord>,
+}
+
+pub fn handler(ctx: Context<SetMaxViewingKeys>, max_viewing_keys: u32) -> Result<()> {
+    require!(
+        max_viewing_keys > 0 && max_viewing_keys <= CreditRecord::MAX_VIEWING_KEYS_LIMIT,
+        PrivateScoreError::InvalidAccountState
+    );
+
+    let credit_record = &mut ctx.accounts.credit_record;
+    credit_record.max_viewing_keys = max_viewing_keys;
+    credit_record.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Viewing key limit for {} set to {}", credit_record.owner, max_viewing_keys);
+    Ok(())
+}
@@ -0,0 +1,32 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! SET POOL ACTIVE - Pause or resume a pool
+//! ═══════════════════════════════════════════════════════════════════════════
+
+use anchor_lang::prelude::*;
+use crate::state::{AdminMultisig, LendingPool};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct SetPoolActive<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub pool: Account<'info, LendingPool>,
+
+    /// Required when the pool has an admin multisig; approvers sign via remaining_accounts
+    pub admin_multisig: Option<Account<'info, AdminMultisig>>,
+}
+
+pub fn handler(ctx: Context<SetPoolActive>, is_active: bool) -> Result<()> {
+    ctx.accounts.pool.require_admin_approval(ctx.accounts.admin_multisig.as_ref(), ctx.remaining_accounts)?;
+
+    let pool = &mut ctx.accounts.pool;
+    pool.is_active = is_active;
+    pool.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Pool {} active: {}", pool.pool_id, is_active);
+    Ok(())
+}
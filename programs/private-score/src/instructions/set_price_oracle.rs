@@ -0,0 +1,59 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! SET PRICE ORACLE - Rotate a pool to a different price feed
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! The new feed must be a PriceFeed created for this pool (see
+//! create_price_feed) and currently fresh. Loans do not store the oracle;
+//! liquidation reads the pool's feed, so open loans price against the new one
+//! from the next call.
+
+use anchor_lang::prelude::*;
+use crate::state::{AdminMultisig, GlobalConfig, LendingPool, PriceFeed};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+#[instruction(new_oracle: Pubkey)]
+pub struct SetPriceOracle<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalConfig::SEED],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        constraint = price_feed.key() == new_oracle @ PrivateScoreError::InvalidOracle,
+        constraint = price_feed.pool == pool.key() @ PrivateScoreError::InvalidOracle
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+
+    /// Required when the pool has an admin multisig; approvers sign via remaining_accounts
+    pub admin_multisig: Option<Account<'info, AdminMultisig>>,
+}
+
+pub fn handler(ctx: Context<SetPriceOracle>, new_oracle: Pubkey) -> Result<()> {
+    ctx.accounts.pool.require_admin_approval(ctx.accounts.admin_multisig.as_ref(), ctx.remaining_accounts)?;
+
+    let clock = Clock::get()?;
+    let price_feed = &ctx.accounts.price_feed;
+    require!(price_feed.price > 0, PrivateScoreError::InvalidOracle);
+    require!(
+        !price_feed.is_stale(clock.unix_timestamp, ctx.accounts.global_config.max_oracle_staleness),
+        PrivateScoreError::StaleOraclePrice
+    );
+
+    let pool = &mut ctx.accounts.pool;
+    let old_oracle = pool.price_oracle;
+    pool.price_oracle = new_oracle;
+    pool.updated_at = clock.unix_timestamp;
+
+    msg!("Pool {} price oracle rotated: {} -> {}", pool.pool_id, old_oracle, new_oracle);
+    Ok(())
+}
@@ -0,0 +1,45 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! SET RECOVERY AUTHORITY - Designate who may recover a lost credit record
+//! ═══════════════════════════════════════════════════════════════════════════
+
+use anchor_lang::prelude::*;
+use crate::state::{CreditRecord, RecoveryCancelled};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct SetRecoveryAuthority<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"credit", owner.key().as_ref()],
+        bump = credit_record.bump,
+        constraint = credit_record.owner == owner.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub credit_record: Account<'info, CreditRecord>,
+}
+
+/// Pass `Pubkey::default()` to disable recovery
+pub fn handler(ctx: Context<SetRecoveryAuthority>, recovery_authority: Pubkey) -> Result<()> {
+    require!(recovery_authority != ctx.accounts.owner.key(), PrivateScoreError::Unauthorized);
+
+    let clock = Clock::get()?;
+    let record_key = ctx.accounts.credit_record.key();
+    let credit_record = &mut ctx.accounts.credit_record;
+
+    // Rotating the authority aborts any recovery the old one started
+    if credit_record.has_pending_recovery() {
+        credit_record.clear_recovery();
+        emit!(RecoveryCancelled {
+            credit_record: record_key,
+            cancelled_by: ctx.accounts.owner.key(),
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    credit_record.recovery_authority = recovery_authority;
+    credit_record.updated_at = clock.unix_timestamp;
+
+    msg!("Recovery authority for {} set to {}", ctx.accounts.owner.key(), recovery_authority);
+    Ok(())
+}
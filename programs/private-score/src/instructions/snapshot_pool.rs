@@ -0,0 +1,43 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! SNAPSHOT POOL - Record utilization, borrows, and rate (permissionless)
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! Anyone may call this, at most once per MIN_SNAPSHOT_INTERVAL per pool.
+
+use anchor_lang::prelude::*;
+use crate::state::{LendingPool, PoolSnapshot, PoolSnapshots};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct SnapshotPool<'info> {
+    pub caller: Signer<'info>,
+
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        mut,
+        seeds = [PoolSnapshots::SEED_PREFIX, pool.key().as_ref()],
+        bump = pool_snapshots.bump
+    )]
+    pub pool_snapshots: Account<'info, PoolSnapshots>,
+}
+
+pub fn handler(ctx: Context<SnapshotPool>) -> Result<()> {
+    let clock = Clock::get()?;
+    let pool = &ctx.accounts.pool;
+    let snapshots = &mut ctx.accounts.pool_snapshots;
+
+    require!(snapshots.can_snapshot(clock.unix_timestamp), PrivateScoreError::InvalidAccountState);
+
+    let snapshot = PoolSnapshot {
+        timestamp: clock.unix_timestamp,
+        utilization: pool.utilization_rate(),
+        total_borrowed: pool.total_borrowed,
+        rate: pool.borrow_rate(),
+    };
+    snapshots.push(snapshot);
+
+    msg!("Pool {} snapshot: utilization {} bps, borrowed {}, rate {} bps",
+        pool.pool_id, snapshot.utilization, snapshot.total_borrowed, snapshot.rate);
+    Ok(())
+}
@@ -0,0 +1,90 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! START LIQUIDATION AUCTION - Flag an undercollateralized loan for auction
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! In auction pools the liquidation bonus starts small and rises over the
+//! auction window, so lenders recover more when liquidators act early.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::state::{GlobalConfig, LendingPool, Loan, LoanStatus, PriceFeed};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct StartLiquidationAuction<'info> {
+    pub keeper: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalConfig::SEED],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        constraint = pool.auction_liquidation @ PrivateScoreError::InvalidAccountState
+    )]
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        mut,
+        constraint = loan.pool == pool.key() @ PrivateScoreError::InvalidAccountState,
+        constraint = loan.status == LoanStatus::Active @ PrivateScoreError::LoanNotActive,
+        constraint = !loan.is_in_auction() @ PrivateScoreError::InvalidAccountState
+    )]
+    pub loan: Account<'info, Loan>,
+
+    #[account(
+        seeds = [b"collateral_vault", loan.key().as_ref()],
+        bump
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+
+    /// Required for cosigned loans; counted with the borrower's collateral, as in liquidate
+    #[account(
+        seeds = [b"cosigner_vault", loan.key().as_ref()],
+        bump
+    )]
+    pub cosigner_vault: Option<Account<'info, TokenAccount>>,
+
+    /// Required once the pool has a price oracle, as in liquidate
+    pub price_oracle: Option<Account<'info, PriceFeed>>,
+}
+
+pub fn handler(ctx: Context<StartLiquidationAuction>) -> Result<()> {
+    let clock = Clock::get()?;
+    let borrow_index = ctx.accounts.pool.accrue_borrow_index(clock.unix_timestamp);
+    let pool = &ctx.accounts.pool;
+    let loan = &mut ctx.accounts.loan;
+
+    loan.accrue_interest(borrow_index, clock.unix_timestamp)?;
+
+    let cosigner_amount = if loan.has_cosigner() {
+        let cosigner_vault = ctx.accounts.cosigner_vault.as_ref().ok_or(PrivateScoreError::InvalidCollateralAccount)?;
+        cosigner_vault.amount
+    } else {
+        0
+    };
+    let collateral_value = pool.collateral_value(
+        ctx.accounts.collateral_vault.amount.saturating_add(cosigner_amount),
+        ctx.accounts.price_oracle.as_ref(),
+        clock.unix_timestamp,
+        ctx.accounts.global_config.max_oracle_staleness,
+    )?;
+    require!(
+        loan.is_liquidatable(
+            collateral_value,
+            loan.effective_liquidation_threshold(pool.liquidation_threshold),
+            clock.unix_timestamp,
+        ),
+        PrivateScoreError::LoanNotLiquidatable
+    );
+
+    loan.start_auction(clock.unix_timestamp);
+
+    msg!("Liquidation auction started for loan {}", ctx.accounts.loan.key());
+    msg!("Bonus ramps {} -> {} bps over {}s",
+        Loan::AUCTION_START_BONUS_BPS, Loan::AUCTION_MAX_BONUS_BPS, Loan::AUCTION_DURATION);
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! SUBMIT PROOF - Verify a credit proof on its own, ahead of borrowing
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! Large proofs plus all the borrow accounts may not fit in one transaction's
+//! size or compute budget. A successful submission creates a short-lived
+//! ProofReceipt that `borrow_with_receipt` redeems without re-sending the proof.
+
+use anchor_lang::prelude::*;
+use crate::state::{CreditRecord, LendingPool, ProofReceipt, ProofReplayCache, TierTable};
+use crate::errors::PrivateScoreError;
+use super::verify_and_borrow::{proven_tier, verify_credit_proof};
+
+#[derive(Accounts)]
+pub struct SubmitProof<'info> {
+    #[account(mut)]
+    pub borrower: Signer<'info>,
+
+    #[account(
+        constraint = pool.is_active @ PrivateScoreError::PoolInactive,
+        constraint = pool.accepts_credit_loans @ PrivateScoreError::CreditLoansNotAccepted
+    )]
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        mut,
+        seeds = [b"credit", credit_record.owner.as_ref()],
+        bump = credit_record.bump,
+        constraint = credit_record.can_borrow_as(&borrower.key()) @ PrivateScoreError::Unauthorized,
+        constraint = credit_record.is_active @ PrivateScoreError::CreditRecordInactive,
+        constraint = !credit_record.frozen @ PrivateScoreError::CreditRecordInactive
+    )]
+    pub credit_record: Account<'info, CreditRecord>,
+
+    #[account(
+        init,
+        payer = borrower,
+        space = ProofReceipt::LEN,
+        seeds = [ProofReceipt::SEED_PREFIX, credit_record.key().as_ref(), &credit_record.nonce.to_le_bytes()],
+        bump
+    )]
+    pub proof_receipt: Account<'info, ProofReceipt>,
+
+    /// Required once the pool has custom credit bands
+    #[account(
+        seeds = [TierTable::SEED_PREFIX, pool.key().as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Option<Account<'info, TierTable>>,
+
+    /// Required once the pool has a proof replay cache; byte-identical proofs are rejected
+    #[account(
+        mut,
+        seeds = [ProofReplayCache::SEED_PREFIX, pool.key().as_ref()],
+        bump = proof_replay_cache.bump
+    )]
+    pub proof_replay_cache: Option<Account<'info, ProofReplayCache>>,
+
+    /// CHECK: Sunspot ZK verifier program; must be the one configured on the pool
+    #[account(
+        constraint = zk_verifier.key() == pool.verifier_program @ PrivateScoreError::VerifierError,
+        constraint = zk_verifier.executable @ PrivateScoreError::VerifierError
+    )]
+    pub zk_verifier: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SubmitProof>, proof: Vec<u8>, public_inputs: Vec<u8>) -> Result<()> {
+    let clock = Clock::get()?;
+    let credit_record = &ctx.accounts.credit_record;
+    require!(credit_record.can_borrow(clock.unix_timestamp), PrivateScoreError::CreditExpired);
+
+    ctx.accounts.pool.require_tier_table(ctx.accounts.tier_table.as_ref())?;
+
+    let proof_hash = verify_credit_proof(&ctx.accounts.pool, &credit_record.commitment, &proof, &public_inputs)?;
+    ctx.accounts.pool.record_proof(ctx.accounts.proof_replay_cache.as_mut(), proof_hash, clock.unix_timestamp)?;
+    let tier = match ctx.accounts.tier_table.as_deref() {
+        Some(table) => proven_tier(table, &public_inputs)?,
+        None => credit_record.tier,
+    };
+
+    let receipt = &mut ctx.accounts.proof_receipt;
+    receipt.credit_record = credit_record.key();
+    receipt.pool = ctx.accounts.pool.key();
+    receipt.commitment = credit_record.commitment;
+    receipt.nonce = credit_record.nonce;
+    receipt.proof_hash = proof_hash;
+    receipt.tier = tier;
+    receipt.created_at = clock.unix_timestamp;
+    receipt.expires_at = clock.unix_timestamp.saturating_add(ProofReceipt::TTL);
+    receipt.bump = ctx.bumps.proof_receipt;
+
+    let credit_record = &mut ctx.accounts.credit_record;
+    credit_record.proofs_verified = credit_record.proofs_verified.saturating_add(1);
+    credit_record.increment_nonce();
+
+    msg!("Proof verified for {}; receipt valid until {}", credit_record.owner, receipt.expires_at);
+    Ok(())
+}
@@ -0,0 +1,92 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! SWEEP DUST - Return rounding leftovers from a closed loan's collateral vault
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! Admin-only and limited to balances below MAX_SWEEPABLE_DUST so it cannot be
+//! used to move real collateral. Residue goes to the borrower when their
+//! collateral account is supplied, otherwise to the pool authority's account.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+use crate::state::{LendingPool, Loan, LoanStatus};
+use crate::errors::PrivateScoreError;
+
+/// Largest residual balance (collateral units) that may be swept
+const MAX_SWEEPABLE_DUST: u64 = 10_000;
+
+#[derive(Accounts)]
+pub struct SweepDust<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = pool.authority == authority.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        constraint = loan.pool == pool.key() @ PrivateScoreError::InvalidAccountState,
+        constraint = loan.status != LoanStatus::Active @ PrivateScoreError::InvalidAccountState
+    )]
+    pub loan: Account<'info, Loan>,
+
+    #[account(
+        mut,
+        seeds = [b"collateral_vault", loan.key().as_ref()],
+        bump
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = borrower_collateral_account.owner == loan.borrower @ PrivateScoreError::InvalidCollateralAccount
+    )]
+    pub borrower_collateral_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = authority_collateral_account.owner == pool.authority @ PrivateScoreError::InvalidCollateralAccount
+    )]
+    pub authority_collateral_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Receives the vault's rent; must be the loan's borrower
+    #[account(mut, address = loan.borrower @ PrivateScoreError::Unauthorized)]
+    pub borrower: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<SweepDust>) -> Result<()> {
+    let dust = ctx.accounts.collateral_vault.amount;
+    require!(dust <= MAX_SWEEPABLE_DUST, PrivateScoreError::InvalidAmount);
+
+    let loan_key = ctx.accounts.loan.key();
+    let seeds = &[b"collateral_vault".as_ref(), loan_key.as_ref(), &[ctx.bumps.collateral_vault]];
+
+    if dust > 0 {
+        let destination = match &ctx.accounts.borrower_collateral_account {
+            Some(account) => account.to_account_info(),
+            None => ctx.accounts.authority_collateral_account.to_account_info(),
+        };
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.collateral_vault.to_account_info(),
+            to: destination,
+            authority: ctx.accounts.collateral_vault.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, &[seeds]),
+            dust,
+        )?;
+    }
+
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.collateral_vault.to_account_info(),
+        destination: ctx.accounts.borrower.to_account_info(),
+        authority: ctx.accounts.collateral_vault.to_account_info(),
+    };
+    token::close_account(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, &[seeds]),
+    )?;
+
+    msg!("Swept {} dust from loan {} and closed its collateral vault", dust, loan_key);
+    Ok(())
+}
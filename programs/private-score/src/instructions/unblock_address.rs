@@ -0,0 +1,38 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! UNBLOCK ADDRESS - Lift a pool's block on an address
+//! ═══════════════════════════════════════════════════════════════════════════
+
+use anchor_lang::prelude::*;
+use crate::state::{AdminMultisig, Blocklist, LendingPool};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct UnblockAddress<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = pool.authority == authority.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        mut,
+        seeds = [Blocklist::SEED_PREFIX, pool.key().as_ref()],
+        bump = blocklist.bump
+    )]
+    pub blocklist: Account<'info, Blocklist>,
+
+    /// Required when the pool has an admin multisig; approvers sign via remaining_accounts
+    pub admin_multisig: Option<Account<'info, AdminMultisig>>,
+}
+
+pub fn handler(ctx: Context<UnblockAddress>, address: Pubkey) -> Result<()> {
+    ctx.accounts.pool.require_admin_approval(ctx.accounts.admin_multisig.as_ref(), ctx.remaining_accounts)?;
+
+    let blocklist = &mut ctx.accounts.blocklist;
+    blocklist.remove(\&address)?;
+    blocklist.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Address {} unblocked in pool {}", address, ctx.accounts.pool.pool_id);
+    Ok(())
+}
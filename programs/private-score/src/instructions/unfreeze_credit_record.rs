@@ -0,0 +1,34 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! UNFREEZE CREDIT RECORD - Lift a compliance hold on a credit record
+//! ═══════════════════════════════════════════════════════════════════════════
+
+use anchor_lang::prelude::*;
+use crate::state::{CreditRecord, GlobalConfig};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct UnfreezeCreditRecord<'info> {
+    pub compliance_authority: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalConfig::SEED],
+        bump = global_config.bump,
+        constraint = global_config.compliance_authority == compliance_authority.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        constraint = credit_record.frozen @ PrivateScoreError::InvalidAccountState
+    )]
+    pub credit_record: Account<'info, CreditRecord>,
+}
+
+pub fn handler(ctx: Context<UnfreezeCreditRecord>) -> Result<()> {
+    let credit_record = &mut ctx.accounts.credit_record;
+    credit_record.frozen = false;
+    credit_record.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Credit record of {} unfrozen by {}", credit_record.owner, ctx.accounts.compliance_authority.key());
+    Ok(())
+}
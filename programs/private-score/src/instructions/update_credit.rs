@@ -3,7 +3,7 @@
 //! ═══════════════════════════════════════════════════════════════════════════
 
 use anchor_lang::prelude::*;
-use crate::state::{CreditRecord, CreditTier};
+use crate::state::{CreditRecord, CreditTier, TierChanged};
 use crate::errors::PrivateScoreError;
 
 #[derive(Accounts)]
@@ -28,8 +28,22 @@ pub fn handler(
     require!(new_commitment != [0u8; 32], PrivateScoreError::InvalidCommitment);
 
     let credit_record = &mut ctx.accounts.credit_record;
+    // A fresh salt on every update keeps successive commitments unlinkable
+    require!(new_commitment != credit_record.commitment, PrivateScoreError::InvalidCommitment);
     let clock = Clock::get()?;
-    let tier_enum = CreditTier::from_u8(new_tier);
+    let tier_enum = CreditTier::try_from_u8(new_tier)?;
+
+    let old_tier = credit_record.tier;
+    if old_tier != tier_enum {
+        credit_record.record_tier_change(tier_enum, clock.unix_timestamp);
+        emit!(TierChanged {
+            credit_record: credit_record.key(),
+            owner: credit_record.owner,
+            old_tier,
+            new_tier: tier_enum,
+            timestamp: clock.unix_timestamp,
+        });
+    }
 
     credit_record.update_commitment(new_commitment, tier_enum, clock.unix_timestamp);
 
@@ -0,0 +1,60 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! UPDATE GLOBAL CONFIG - Super-admin changes to program-wide parameters
+//! ═══════════════════════════════════════════════════════════════════════════
+
+use anchor_lang::prelude::*;
+use crate::state::GlobalConfig;
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct UpdateGlobalConfig<'info> {
+    pub super_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GlobalConfig::SEED],
+        bump = global_config.bump,
+        constraint = global_config.super_admin == super_admin.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+/// Only fields that are `Some` are updated
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct GlobalConfigUpdate {
+    pub super_admin: Option<Pubkey>,
+    pub compliance_authority: Option<Pubkey>,
+    pub score_provider: Option<Pubkey>,
+    pub fee_recipient: Option<Pubkey>,
+    pub max_oracle_staleness: Option<i64>,
+    pub swap_programs: Option<[Pubkey; 4]>,
+}
+
+pub fn handler(ctx: Context<UpdateGlobalConfig>, params: GlobalConfigUpdate) -> Result<()> {
+    let config = &mut ctx.accounts.global_config;
+
+    if let Some(super_admin) = params.super_admin {
+        config.super_admin = super_admin;
+    }
+    if let Some(compliance_authority) = params.compliance_authority {
+        config.compliance_authority = compliance_authority;
+    }
+    if let Some(score_provider) = params.score_provider {
+        config.score_provider = score_provider;
+    }
+    if let Some(fee_recipient) = params.fee_recipient {
+        config.fee_recipient = fee_recipient;
+    }
+    if let Some(max_oracle_staleness) = params.max_oracle_staleness {
+        require!(max_oracle_staleness > 0, PrivateScoreError::InvalidAccountState);
+        config.max_oracle_staleness = max_oracle_staleness;
+    }
+    if let Some(swap_programs) = params.swap_programs {
+        config.swap_programs = swap_programs;
+    }
+
+    config.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Global config updated by {}", ctx.accounts.super_admin.key());
+    Ok(())
+}
@@ -0,0 +1,183 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! UPDATE POOL PARAMS - Authority-gated pool configuration changes
+//! ═══════════════════════════════════════════════════════════════════════════
+
+use anchor_lang::prelude::*;
+use crate::state::{AdminMultisig, CreditTier, LendingPool, SeizureOrder};
+use crate::errors::PrivateScoreError;
+use super::verify_and_borrow::{PUBLIC_INPUT_CIRCUIT_VERSION, PUBLIC_INPUT_FIELD_LEN};
+
+#[derive(Accounts)]
+pub struct UpdatePoolParams<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub pool: Account<'info, LendingPool>,
+
+    /// Required when the pool has an admin multisig; approvers sign via remaining_accounts
+    pub admin_multisig: Option<Account<'info, AdminMultisig>>,
+}
+
+/// Only fields that are `Some` are updated
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct PoolParamsUpdate {
+    pub base_collateral_ratio: Option<u16>,
+    pub credit_collateral_ratio: Option<u16>,
+    pub liquidation_threshold: Option<u16>,
+    pub min_opening_health_factor: Option<u16>,
+    pub max_interest_multiple: Option<u16>,
+    pub auction_liquidation: Option<bool>,
+    pub close_factor_bps: Option<u16>,
+    pub seizure_order: Option<SeizureOrder>,
+    pub min_tier_for_access: Option<CreditTier>,
+    pub require_proof_for_regulatory: Option<bool>,
+    pub insurance_fee_bps: Option<u16>,
+    pub reserve_factor_bps: Option<u16>,
+    pub min_reserve_bps: Option<u16>,
+    pub tier_max_borrow_bps: Option<[u16; 6]>,
+    pub tier_liquidation_discount_bps: Option<[u16; 6]>,
+    pub tier_collateral_discount_bps: Option<[u16; 6]>,
+    pub tier_on_time_rebate_bps: Option<[u16; 6]>,
+    pub interest_free_period: Option<i64>,
+    pub max_loan_duration: Option<i64>,
+    pub liquidation_cooldown: Option<i64>,
+    pub keeper_reward: Option<u64>,
+    pub dust_threshold: Option<u64>,
+    pub max_borrow_per_tx: Option<u64>,
+    pub circuit_version: Option<u16>,
+    pub verifier_program: Option<Pubkey>,
+    pub max_proof_len: Option<u32>,
+    pub max_public_inputs_len: Option<u32>,
+}
+
+pub fn handler(ctx: Context<UpdatePoolParams>, params: PoolParamsUpdate) -> Result<()> {
+    ctx.accounts.pool.require_admin_approval(ctx.accounts.admin_multisig.as_ref(), ctx.remaining_accounts)?;
+
+    let pool = &mut ctx.accounts.pool;
+
+    let base_collateral_ratio = params.base_collateral_ratio.unwrap_or(pool.base_collateral_ratio);
+    let credit_collateral_ratio = params.credit_collateral_ratio.unwrap_or(pool.credit_collateral_ratio);
+    require!(credit_collateral_ratio >= 10000, PrivateScoreError::InvalidCollateralRatio);
+    require!(credit_collateral_ratio <= base_collateral_ratio, PrivateScoreError::InvalidCollateralRatio);
+    let liquidation_threshold = params.liquidation_threshold.unwrap_or(pool.liquidation_threshold);
+    require!(liquidation_threshold >= 10000, PrivateScoreError::InvalidCollateralRatio);
+    require!(liquidation_threshold < credit_collateral_ratio, PrivateScoreError::InvalidCollateralRatio);
+    pool.base_collateral_ratio = base_collateral_ratio;
+    pool.credit_collateral_ratio = credit_collateral_ratio;
+    pool.liquidation_threshold = liquidation_threshold;
+
+    // New positions must open strictly above the liquidation threshold; this is also
+    // the floor for tier-discounted credit ratios
+    let min_opening_health_factor = params.min_opening_health_factor.unwrap_or(pool.min_opening_health_factor);
+    require!(min_opening_health_factor > liquidation_threshold, PrivateScoreError::InvalidCollateralRatio);
+    pool.min_opening_health_factor = min_opening_health_factor;
+
+    if let Some(max_interest_multiple) = params.max_interest_multiple {
+        pool.max_interest_multiple = max_interest_multiple;
+    }
+    if let Some(auction_liquidation) = params.auction_liquidation {
+        pool.auction_liquidation = auction_liquidation;
+    }
+    if let Some(close_factor_bps) = params.close_factor_bps {
+        require!(close_factor_bps <= 10000, PrivateScoreError::InvalidCollateralRatio);
+        pool.close_factor_bps = close_factor_bps;
+    }
+    if let Some(seizure_order) = params.seizure_order {
+        pool.seizure_order = seizure_order;
+    }
+    if let Some(min_tier_for_access) = params.min_tier_for_access {
+        pool.min_tier_for_access = min_tier_for_access;
+    }
+    if let Some(require_proof_for_regulatory) = params.require_proof_for_regulatory {
+        pool.require_proof_for_regulatory = require_proof_for_regulatory;
+    }
+
+    if let Some(insurance_fee_bps) = params.insurance_fee_bps {
+        require!(insurance_fee_bps <= 10000, PrivateScoreError::InvalidInterestRate);
+        pool.insurance_fee_bps = insurance_fee_bps;
+    }
+    if let Some(reserve_factor_bps) = params.reserve_factor_bps {
+        pool.reserve_factor_bps = reserve_factor_bps;
+    }
+    // Both slices come out of the same repaid interest
+    require!(
+        pool.insurance_fee_bps as u32 + pool.reserve_factor_bps as u32 <= 10000,
+        PrivateScoreError::InvalidInterestRate
+    );
+    if let Some(min_reserve_bps) = params.min_reserve_bps {
+        require!(min_reserve_bps <= 10000, PrivateScoreError::InvalidCollateralRatio);
+        pool.min_reserve_bps = min_reserve_bps;
+    }
+
+    if let Some(tier_max_borrow_bps) = params.tier_max_borrow_bps {
+        require!(tier_max_borrow_bps.iter().all(|bps| *bps <= 10000), PrivateScoreError::InvalidCollateralRatio);
+        pool.tier_max_borrow_bps = tier_max_borrow_bps;
+    }
+    if let Some(tier_liquidation_discount_bps) = params.tier_liquidation_discount_bps {
+        require!(tier_liquidation_discount_bps.iter().all(|bps| *bps <= 10000), PrivateScoreError::InvalidCollateralRatio);
+        pool.tier_liquidation_discount_bps = tier_liquidation_discount_bps;
+    }
+    if let Some(tier_collateral_discount_bps) = params.tier_collateral_discount_bps {
+        require!(tier_collateral_discount_bps.iter().all(|bps| *bps <= 10000), PrivateScoreError::InvalidCollateralRatio);
+        pool.tier_collateral_discount_bps = tier_collateral_discount_bps;
+    }
+    if let Some(tier_on_time_rebate_bps) = params.tier_on_time_rebate_bps {
+        require!(tier_on_time_rebate_bps.iter().all(|bps| *bps <= 10000), PrivateScoreError::InvalidInterestRate);
+        pool.tier_on_time_rebate_bps = tier_on_time_rebate_bps;
+    }
+
+    if let Some(interest_free_period) = params.interest_free_period {
+        require!(interest_free_period >= 0, PrivateScoreError::InvalidLoanDuration);
+        pool.interest_free_period = interest_free_period;
+    }
+
+    if let Some(max_loan_duration) = params.max_loan_duration {
+        require!(max_loan_duration > 0, PrivateScoreError::InvalidLoanDuration);
+        pool.max_loan_duration = max_loan_duration;
+    }
+    if let Some(liquidation_cooldown) = params.liquidation_cooldown {
+        require!(liquidation_cooldown >= 0, PrivateScoreError::InvalidLoanDuration);
+        pool.liquidation_cooldown = liquidation_cooldown;
+    }
+    if let Some(keeper_reward) = params.keeper_reward {
+        pool.keeper_reward = keeper_reward;
+    }
+    if let Some(dust_threshold) = params.dust_threshold {
+        pool.dust_threshold = dust_threshold;
+    }
+    if let Some(max_borrow_per_tx) = params.max_borrow_per_tx {
+        pool.max_borrow_per_tx = max_borrow_per_tx;
+    }
+
+    if let Some(circuit_version) = params.circuit_version {
+        // Versions only move forward so retired circuits cannot be re-enabled
+        require!(circuit_version > pool.circuit_version, PrivateScoreError::CircuitMismatch);
+        pool.circuit_version = circuit_version;
+    }
+
+    if let Some(verifier_program) = params.verifier_program {
+        pool.verifier_program = verifier_program;
+    }
+
+    // Lower bounds match the minimum sizes verify_and_borrow accepts: the public
+    // inputs must reach the circuit version field
+    if let Some(max_proof_len) = params.max_proof_len {
+        require!(max_proof_len >= 64, PrivateScoreError::InvalidProof);
+        pool.max_proof_len = max_proof_len;
+    }
+    if let Some(max_public_inputs_len) = params.max_public_inputs_len {
+        require!(
+            max_public_inputs_len as usize >= (PUBLIC_INPUT_CIRCUIT_VERSION + 1) * PUBLIC_INPUT_FIELD_LEN,
+            PrivateScoreError::InvalidPublicInputs
+        );
+        pool.max_public_inputs_len = max_public_inputs_len;
+    }
+
+    pool.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Pool {} parameters updated", pool.pool_id);
+    Ok(())
+}
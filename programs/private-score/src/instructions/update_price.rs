@@ -0,0 +1,31 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! UPDATE PRICE - Feed updater pushes a new collateral price
+//! ═══════════════════════════════════════════════════════════════════════════
+
+use anchor_lang::prelude::*;
+use crate::state::PriceFeed;
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct UpdatePrice<'info> {
+    pub updater: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PriceFeed::SEED_PREFIX, price_feed.pool.as_ref(), updater.key().as_ref()],
+        bump = price_feed.bump,
+        constraint = price_feed.updater == updater.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+}
+
+pub fn handler(ctx: Context<UpdatePrice>, price: u64) -> Result<()> {
+    require!(price > 0, PrivateScoreError::InvalidOracle);
+
+    let price_feed = &mut ctx.accounts.price_feed;
+    price_feed.price = price;
+    price_feed.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Price feed {} updated: {}", price_feed.key(), price);
+    Ok(())
+}
@@ -0,0 +1,38 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! UPDATE TIER TABLE - Replace a pool's custom credit bands
+//! ═══════════════════════════════════════════════════════════════════════════
+
+use anchor_lang::prelude::*;
+use crate::state::{AdminMultisig, LendingPool, TierBand, TierTable};
+use crate::errors::PrivateScoreError;
+
+#[derive(Accounts)]
+pub struct UpdateTierTable<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = pool.authority == authority.key() @ PrivateScoreError::Unauthorized
+    )]
+    pub pool: Account<'info, LendingPool>,
+
+    #[account(
+        mut,
+        seeds = [TierTable::SEED_PREFIX, pool.key().as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Account<'info, TierTable>,
+
+    /// Required when the pool has an admin multisig; approvers sign via remaining_accounts
+    pub admin_multisig: Option<Account<'info, AdminMultisig>>,
+}
+
+pub fn handler(ctx: Context<UpdateTierTable>, bands: Vec<TierBand>) -> Result<()> {
+    ctx.accounts.pool.require_admin_approval(ctx.accounts.admin_multisig.as_ref(), ctx.remaining_accounts)?;
+
+    let table = &mut ctx.accounts.tier_table;
+    table.set_bands(&bands)?;
+    table.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Tier table for pool {} now has {} bands", ctx.accounts.pool.pool_id, table.num_bands);
+    Ok(())
+}
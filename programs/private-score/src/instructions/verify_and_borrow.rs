@@ -8,17 +8,53 @@
 //!
 //! The proof is verified via Sunspot (Noir proof verifier on Solana).
 //! The actual credit score is NEVER revealed - only that it meets threshold.
+//!
+//! A verified proof opens a short credit session on the record; follow-up
+//! borrows within the session may pass an empty proof instead of re-proving.
+//! For verification in a separate transaction see `submit_proof` and
+//! `borrow_with_receipt`.
+//!
+//! Verification is compute-heavy: clients must request at least
+//! MIN_VERIFY_COMPUTE_UNITS via a ComputeBudget instruction, otherwise the
+//! proof is rejected up front with VerifierError.
+//!
+//! Pools with a TierTable derive the tier from the proven minimum score
+//! instead of the record's default band; such pools always need a fresh proof.
+//!
+//! The signer may be the record owner, its beneficiary or the owner's borrow
+//! delegate. The signer posts the collateral; the loan and the borrowed funds
+//! belong to the record's beneficiary (the owner unless one is set).
+//!
+//! An optional cosigner can pledge part of the required collateral from their
+//! own account; it is held in a separate vault and seized after the borrower's.
 
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::compute_units::sol_remaining_compute_units;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::{CreditRecord, LendingPool, Loan, LoanType, LoanStatus};
+use crate::state::{Blocklist, CollateralWhitelist, CreditRecord, CreditTier, GlobalConfig, LendingPool, Loan, LoanType, LoanStatus, PriceFeed, ProofReplayCache, TierTable};
 use crate::errors::PrivateScoreError;
 
+/// Public inputs are 32-byte big-endian field elements in this order
+pub(crate) const PUBLIC_INPUT_FIELD_LEN: usize = 32;
+const PUBLIC_INPUT_MIN_SCORE: usize = 1;
+pub(crate) const PUBLIC_INPUT_CIRCUIT_VERSION: usize = 4; // commitment, min_score, pool_id, nonce, circuit_version
+
+/// Compute units that must remain before proof verification starts
+pub const MIN_VERIFY_COMPUTE_UNITS: u64 = 250_000;
+
 #[derive(Accounts)]
 pub struct VerifyAndBorrow<'info> {
+    /// Record owner, beneficiary, or the borrow delegate set on the record
     #[account(mut)]
     pub borrower: Signer<'info>,
 
+    #[account(
+        seeds = [GlobalConfig::SEED],
+        bump = global_config.bump,
+        constraint = !global_config.global_paused @ PrivateScoreError::ProtocolPaused
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
     #[account(
         mut,
         constraint = pool.is_active @ PrivateScoreError::PoolInactive,
@@ -28,10 +64,11 @@ pub struct VerifyAndBorrow<'info> {
 
     #[account(
         mut,
-        seeds = [b"credit", borrower.key().as_ref()],
+        seeds = [b"credit", credit_record.owner.as_ref()],
         bump = credit_record.bump,
-        constraint = credit_record.owner == borrower.key() @ PrivateScoreError::Unauthorized,
-        constraint = credit_record.is_active @ PrivateScoreError::CreditRecordInactive
+        constraint = credit_record.can_borrow_as(&borrower.key()) @ PrivateScoreError::Unauthorized,
+        constraint = credit_record.is_active @ PrivateScoreError::CreditRecordInactive,
+        constraint = !credit_record.frozen @ PrivateScoreError::CreditRecordInactive
     )]
     pub credit_record: Account<'info, CreditRecord>,
 
@@ -39,7 +76,7 @@ pub struct VerifyAndBorrow<'info> {
         init,
         payer = borrower,
         space = Loan::LEN,
-        seeds = [b"loan", pool.key().as_ref(), borrower.key().as_ref(), &pool.active_loans.to_le_bytes()],
+        seeds = [b"loan", pool.key().as_ref(), credit_record.borrower_key().as_ref(), &pool.active_loans.to_le_bytes()],
         bump
     )]
     pub loan: Account<'info, Loan>,
@@ -52,13 +89,32 @@ pub struct VerifyAndBorrow<'info> {
 
     #[account(
         mut,
-        constraint = borrower_token_account.mint == pool.token_mint @ PrivateScoreError::InvalidTokenMint
+        constraint = borrower_token_account.mint == pool.loan_mint @ PrivateScoreError::InvalidTokenMint,
+        constraint = borrower_token_account.owner == credit_record.borrower_key() @ PrivateScoreError::Unauthorized
     )]
     pub borrower_token_account: Account<'info, TokenAccount>,
 
+    /// Extra collateral mints accepted by the pool, if it has a whitelist
+    #[account(
+        seeds = [CollateralWhitelist::SEED_PREFIX, pool.key().as_ref()],
+        bump = collateral_whitelist.bump
+    )]
+    pub collateral_whitelist: Option<Account<'info, CollateralWhitelist>>,
+
+    /// Required once the pool has a blocklist
+    #[account(
+        seeds = [Blocklist::SEED_PREFIX, pool.key().as_ref()],
+        bump = blocklist.bump
+    )]
+    pub blocklist: Option<Account<'info, Blocklist>>,
+
+    /// Required once the pool has a price oracle
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+
     #[account(
         mut,
-        constraint = collateral_account.owner == borrower.key() @ PrivateScoreError::InvalidCollateralAccount
+        constraint = collateral_account.owner == borrower.key() @ PrivateScoreError::InvalidCollateralAccount,
+        constraint = pool.accepts_collateral(&collateral_account.mint, collateral_whitelist.as_deref()) @ PrivateScoreError::InvalidTokenMint
     )]
     pub collateral_account: Account<'info, TokenAccount>,
 
@@ -69,7 +125,42 @@ pub struct VerifyAndBorrow<'info> {
     )]
     pub collateral_vault: Account<'info, TokenAccount>,
 
-    /// CHECK: Sunspot ZK verifier program (would be verified in production)
+    /// Required once the pool has custom credit bands
+    #[account(
+        seeds = [TierTable::SEED_PREFIX, pool.key().as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Option<Account<'info, TierTable>>,
+
+    /// Guarantor sharing liability; required when `cosigner_collateral > 0`
+    pub cosigner: Option<Signer<'info>>,
+
+    #[account(
+        mut,
+        constraint = cosigner_collateral_account.mint == collateral_account.mint @ PrivateScoreError::InvalidTokenMint
+    )]
+    pub cosigner_collateral_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"cosigner_vault", loan.key().as_ref()],
+        bump
+    )]
+    pub cosigner_vault: Option<Account<'info, TokenAccount>>,
+
+    /// Required once the pool has a proof replay cache; byte-identical proofs are rejected
+    #[account(
+        mut,
+        seeds = [ProofReplayCache::SEED_PREFIX, pool.key().as_ref()],
+        bump = proof_replay_cache.bump
+    )]
+    pub proof_replay_cache: Option<Account<'info, ProofReplayCache>>,
+
+    /// CHECK: Sunspot ZK verifier program; must be the one configured on the pool
+    #[account(
+        constraint = zk_verifier.key() == pool.verifier_program @ PrivateScoreError::VerifierError,
+        constraint = zk_verifier.executable @ PrivateScoreError::VerifierError
+    )]
     pub zk_verifier: AccountInfo<'info>,
 
     pub system_program: Program<'info, System>,
@@ -80,19 +171,39 @@ pub struct VerifyAndBorrow<'info> {
 pub fn handler(
     ctx: Context<VerifyAndBorrow>,
     amount: u64,
+    max_collateral: u64,
+    cosigner_collateral: u64,
+    duration: i64,
+    num_installments: u16,
+    installment_interval: i64,
     proof: Vec<u8>,
     public_inputs: Vec<u8>,
 ) -> Result<()> {
     let clock = Clock::get()?;
+    let borrow_index = ctx.accounts.pool.accrue_borrow_index(clock.unix_timestamp);
     let pool = &ctx.accounts.pool;
     let credit_record = &ctx.accounts.credit_record;
 
     // Validate basic requirements
     require!(amount > 0, PrivateScoreError::InvalidAmount);
-    require!(pool.has_liquidity(amount), PrivateScoreError::InsufficientLiquidity);
+    require!(pool.within_borrow_cap(amount), PrivateScoreError::BorrowLimitExceeded);
+    pool.require_not_blocked(ctx.accounts.blocklist.as_ref(), &ctx.accounts.borrower.key())?;
+    pool.require_not_blocked(ctx.accounts.blocklist.as_ref(), &credit_record.borrower_key())?;
+    let duration = pool.effective_loan_duration(duration)?;
+    require!(num_installments == 0 || installment_interval > 0, PrivateScoreError::InvalidLoanDuration);
+    require!(
+        installment_interval.saturating_mul(num_installments as i64) <= duration,
+        PrivateScoreError::InvalidLoanDuration
+    );
+    require!(pool.has_liquidity(amount, ctx.accounts.vault.amount), PrivateScoreError::InsufficientLiquidity);
     require!(credit_record.can_borrow(clock.unix_timestamp), PrivateScoreError::CreditExpired);
-    require!(!proof.is_empty(), PrivateScoreError::InvalidProof);
-    require!(!public_inputs.is_empty(), PrivateScoreError::InvalidPublicInputs);
+    require!(!credit_record.in_liquidation_cooldown(clock.unix_timestamp), PrivateScoreError::BorrowCooldownActive);
+
+    pool.require_tier_table(ctx.accounts.tier_table.as_ref())?;
+    let tier_table = ctx.accounts.tier_table.as_deref();
+    let use_session = proof.is_empty()
+        && tier_table.is_none()
+        && credit_record.has_active_session(&pool.key(), clock.unix_timestamp);
 
     // ═══════════════════════════════════════════════════════════════════════
     // ZK PROOF VERIFICATION
@@ -103,31 +214,111 @@ pub fn handler(
     // The proof demonstrates: score >= min_score WITHOUT revealing score
     // Public inputs contain: commitment, min_score, pool_id, nonce, timestamp
     
-    let proof_valid = verify_zk_proof(&proof, &public_inputs, &credit_record.commitment)?;
-    require!(proof_valid, PrivateScoreError::ProofVerificationFailed);
+    let proof_hash = if use_session {
+        credit_record.session_proof_hash
+    } else {
+        let proof_hash = verify_credit_proof(pool, &credit_record.commitment, &proof, &public_inputs)?;
+        pool.record_proof(ctx.accounts.proof_replay_cache.as_mut(), proof_hash, clock.unix_timestamp)?;
+        proof_hash
+    };
+    let tier = match tier_table {
+        Some(table) => proven_tier(table, &public_inputs)?,
+        None => credit_record.tier,
+    };
+    require!(pool.tier_has_access(tier), PrivateScoreError::TierDoesNotQualify);
 
     // ═══════════════════════════════════════════════════════════════════════
     // CALCULATE COLLATERAL (REDUCED RATE)
     // ═══════════════════════════════════════════════════════════════════════
-    let collateral_ratio = pool.credit_collateral_ratio; // 120% instead of 150%
-    let required_collateral = (amount as u128 * collateral_ratio as u128 / 10000) as u64;
+    // 120% instead of 150%, less any discount for higher tiers
+    let collateral_ratio = pool.collateral_ratio_for_tier(tier);
+    let required_collateral = pool.required_collateral_at(amount, collateral_ratio);
+
+    // A cosigner covers part of the requirement; the borrower posts the rest
+    require!(cosigner_collateral <= required_collateral, PrivateScoreError::InvalidAmount);
+    let borrower_collateral = required_collateral - cosigner_collateral;
+
+    // New positions need a buffer above the liquidation threshold
+    require!(
+        pool.opening_health_factor(required_collateral, amount) >= pool.min_opening_health_factor as u64,
+        PrivateScoreError::HealthFactorTooLow
+    );
+
+    pool.require_collateral_value(
+        &ctx.accounts.collateral_account.mint,
+        required_collateral,
+        amount,
+        collateral_ratio,
+        ctx.accounts.price_feed.as_ref(),
+        clock.unix_timestamp,
+        ctx.accounts.global_config.max_oracle_staleness,
+    )?;
+
+    // Guard against pool parameters changing after the user signed
+    require!(borrower_collateral <= max_collateral, PrivateScoreError::SlippageExceeded);
 
     // Verify borrower has sufficient collateral
     require!(
-        ctx.accounts.collateral_account.amount >= required_collateral,
+        ctx.accounts.collateral_account.amount >= borrower_collateral,
         PrivateScoreError::InsufficientCollateral
     );
 
+    // Higher tiers may borrow a larger share of the collateral actually locked
+    require!(
+        amount <= pool.max_borrow_for_tier(tier, required_collateral),
+        PrivateScoreError::BorrowLimitExceeded
+    );
+
     // ═══════════════════════════════════════════════════════════════════════
     // TRANSFER COLLATERAL
     // ═══════════════════════════════════════════════════════════════════════
+    let vault_before = ctx.accounts.collateral_vault.amount;
     let cpi_accounts = Transfer {
         from: ctx.accounts.collateral_account.to_account_info(),
         to: ctx.accounts.collateral_vault.to_account_info(),
         authority: ctx.accounts.borrower.to_account_info(),
     };
     let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
-    token::transfer(cpi_ctx, required_collateral)?;
+    token::transfer(cpi_ctx, borrower_collateral)?;
+
+    // Fee-on-transfer mints deliver less than was sent; the loan must not open short
+    ctx.accounts.collateral_vault.reload()?;
+    require!(
+        ctx.accounts.collateral_vault.amount.saturating_sub(vault_before) >= borrower_collateral,
+        PrivateScoreError::InsufficientCollateral
+    );
+
+    let cosigner_key = if cosigner_collateral > 0 {
+        let (Some(cosigner), Some(cosigner_account), Some(cosigner_vault)) = (
+            &ctx.accounts.cosigner,
+            &ctx.accounts.cosigner_collateral_account,
+            &mut ctx.accounts.cosigner_vault,
+        ) else {
+            return err!(PrivateScoreError::Unauthorized);
+        };
+        require!(cosigner.key() != ctx.accounts.borrower.key(), PrivateScoreError::Unauthorized);
+        require!(cosigner_account.owner == cosigner.key(), PrivateScoreError::InvalidCollateralAccount);
+        require!(cosigner_account.amount >= cosigner_collateral, PrivateScoreError::InsufficientCollateral);
+
+        let cosigner_vault_before = cosigner_vault.amount;
+        let cpi_accounts = Transfer {
+            from: cosigner_account.to_account_info(),
+            to: cosigner_vault.to_account_info(),
+            authority: cosigner.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+        token::transfer(cpi_ctx, cosigner_collateral)?;
+
+        // Same fee-on-transfer guard as the borrower's collateral
+        cosigner_vault.reload()?;
+        require!(
+            cosigner_vault.amount.saturating_sub(cosigner_vault_before) >= cosigner_collateral,
+            PrivateScoreError::InsufficientCollateral
+        );
+        cosigner.key()
+    } else {
+        Pubkey::default()
+    };
 
     // ═══════════════════════════════════════════════════════════════════════
     // TRANSFER BORROWED FUNDS
@@ -152,52 +343,113 @@ pub fn handler(
     // CREATE LOAN RECORD
     // ═══════════════════════════════════════════════════════════════════════
     let loan = &mut ctx.accounts.loan;
-    loan.borrower = ctx.accounts.borrower.key();
+    loan.borrower = ctx.accounts.credit_record.borrower_key();
     loan.pool = ctx.accounts.pool.key();
     loan.principal = amount;
     loan.interest_accrued = 0;
     loan.amount_repaid = 0;
-    loan.collateral_locked = required_collateral;
+    loan.collateral_locked = borrower_collateral;
+    loan.cosigner = cosigner_key;
+    loan.cosigner_collateral = cosigner_collateral;
     loan.collateral_mint = ctx.accounts.collateral_account.mint;
     loan.collateral_ratio = collateral_ratio;
-    loan.interest_rate = pool.interest_rate;
+    loan.liquidation_threshold = pool.liquidation_threshold_for_tier(tier);
+    loan.interest_rate = pool.borrow_rate();
+    loan.max_interest_multiple = pool.max_interest_multiple;
     loan.loan_type = LoanType::CreditVerified;
     loan.status = LoanStatus::Active;
-    loan.proof_hash = hash_proof(&proof);
+    loan.proof_hash = proof_hash;
     loan.credit_commitment = credit_record.commitment;
     loan.created_at = clock.unix_timestamp;
     loan.last_accrual_at = clock.unix_timestamp;
+    loan.entry_index = borrow_index;
+    loan.interest_free_until = clock.unix_timestamp.saturating_add(pool.interest_free_period);
+    loan.duration = duration;
+    loan.due_date = clock.unix_timestamp.saturating_add(duration);
+    loan.set_installment_schedule(num_installments, installment_interval);
     loan.bump = ctx.bumps.loan;
 
+    // Savings vs a standard loan, accumulated for protocol-wide reporting
+    let standard_collateral = pool.get_required_collateral(amount, false);
+    let savings = loan.collateral_savings(standard_collateral);
+
     // Update pool state
     let pool = &mut ctx.accounts.pool;
-    pool.total_borrowed = pool.total_borrowed.saturating_add(amount);
-    pool.active_loans = pool.active_loans.saturating_add(1);
+    pool.open_loan(amount, loan.interest_rate);
+    pool.total_collateral_savings = pool.total_collateral_savings.saturating_add(savings);
     pool.updated_at = clock.unix_timestamp;
 
     // Update credit record
     let credit_record = &mut ctx.accounts.credit_record;
     credit_record.record_loan(amount);
-    credit_record.proofs_verified = credit_record.proofs_verified.saturating_add(1);
-    credit_record.increment_nonce();
-
-    // Calculate and log savings
-    let standard_collateral = (amount as u128 * pool.base_collateral_ratio as u128 / 10000) as u64;
-    let savings = standard_collateral.saturating_sub(required_collateral);
+    if use_session {
+        credit_record.consume_session();
+    } else {
+        credit_record.proofs_verified = credit_record.proofs_verified.saturating_add(1);
+        credit_record.increment_nonce();
+        credit_record.open_session(ctx.accounts.pool.key(), proof_hash, 1, clock.unix_timestamp);
+        credit_record.renew_if_enabled(clock.unix_timestamp);
+    }
 
     msg!("═══════════════════════════════════════════════════════════════");
     msg!("ZK-VERIFIED LOAN CREATED");
     msg!("═══════════════════════════════════════════════════════════════");
-    msg!("Borrower: {}", ctx.accounts.borrower.key());
+    msg!("Borrower: {}", ctx.accounts.loan.borrower);
     msg!("Amount: {} tokens", amount);
     msg!("Collateral: {} ({}%)", required_collateral, collateral_ratio / 100);
+    if cosigner_collateral > 0 {
+        msg!("Cosigner {} pledged: {}", cosigner_key, cosigner_collateral);
+    }
     msg!("Savings vs standard: {} tokens", savings);
-    msg!("Proof verified: ✓");
+    if use_session {
+        msg!("Credit session borrow: {} remaining", credit_record.session_borrows_remaining);
+    } else {
+        msg!("Proof verified: ✓");
+    }
     msg!("═══════════════════════════════════════════════════════════════");
 
     Ok(())
 }
 
+/// Full proof check shared by every instruction that accepts a proof:
+/// size limits, circuit version, compute headroom, then verification.
+/// Returns the proof hash for the audit trail.
+pub(crate) fn verify_credit_proof(
+    pool: &LendingPool,
+    commitment: &[u8; 32],
+    proof: &[u8],
+    public_inputs: &[u8],
+) -> Result<[u8; 32]> {
+    require!(!proof.is_empty(), PrivateScoreError::InvalidProof);
+    require!(!public_inputs.is_empty(), PrivateScoreError::InvalidPublicInputs);
+    pool.check_proof_size(proof, public_inputs)?;
+
+    // Proofs from a superseded circuit are rejected outright
+    let circuit_version = public_input_u64(public_inputs, PUBLIC_INPUT_CIRCUIT_VERSION)?;
+    require!(circuit_version == pool.circuit_version as u64, PrivateScoreError::CircuitMismatch);
+
+    // Fail clearly instead of running out of compute mid-verification
+    require!(sol_remaining_compute_units() >= MIN_VERIFY_COMPUTE_UNITS, PrivateScoreError::VerifierError);
+
+    let proof_valid = verify_zk_proof(proof, public_inputs, commitment)?;
+    require!(proof_valid, PrivateScoreError::ProofVerificationFailed);
+    Ok(hash_proof(proof))
+}
+
+/// Check a proof that the committed score meets `required_score`, for disclosure.
+/// Malformed input is an error; a well-formed proof that fails verification is `false`.
+pub(crate) fn verify_score_predicate(
+    commitment: &[u8; 32],
+    required_score: u16,
+    proof: &[u8],
+    public_inputs: &[u8],
+) -> Result<bool> {
+    require!(!proof.is_empty(), PrivateScoreError::InvalidProof);
+    let proven_score = public_input_u64(public_inputs, PUBLIC_INPUT_MIN_SCORE)?;
+    require!(proven_score == required_score as u64, PrivateScoreError::InvalidPublicInputs);
+    verify_zk_proof(proof, public_inputs, commitment)
+}
+
 /// Verify ZK proof (placeholder - would CPI to Sunspot in production)
 fn verify_zk_proof(
     proof: &[u8],
@@ -218,6 +470,23 @@ fn verify_zk_proof(
     Ok(true)
 }
 
+/// Tier under the pool's custom bands for the score the proof attests to
+pub(crate) fn proven_tier(table: &TierTable, public_inputs: &[u8]) -> Result<CreditTier> {
+    let min_score = public_input_u64(public_inputs, PUBLIC_INPUT_MIN_SCORE)?;
+    let tier = table.tier_for_score(min_score);
+    require!(table.qualifies(tier), PrivateScoreError::TierDoesNotQualify);
+    Ok(tier)
+}
+
+/// Read the low 8 bytes of the public input field at `index`
+fn public_input_u64(public_inputs: &[u8], index: usize) -> Result<u64> {
+    let end = (index + 1) * PUBLIC_INPUT_FIELD_LEN;
+    require!(public_inputs.len() >= end, PrivateScoreError::InvalidPublicInputs);
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&public_inputs[end - 8..end]);
+    Ok(u64::from_be_bytes(bytes))
+}
+
 /// Hash the proof for storage (for audit trail)
 fn hash_proof(proof: &[u8]) -> [u8; 32] {
     use anchor_lang::solana_program::hash::hash;
@@ -4,7 +4,7 @@
 
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::LendingPool;
+use crate::state::{LenderPosition, LendingPool};
 use crate::errors::PrivateScoreError;
 
 #[derive(Accounts)]
@@ -20,7 +20,14 @@ pub struct Withdraw<'info> {
 
     #[account(
         mut,
-        constraint = lender_token_account.mint == pool.token_mint @ PrivateScoreError::InvalidTokenMint
+        seeds = [LenderPosition::SEED_PREFIX, pool.key().as_ref(), lender.key().as_ref()],
+        bump = lender_position.bump
+    )]
+    pub lender_position: Account<'info, LenderPosition>,
+
+    #[account(
+        mut,
+        constraint = lender_token_account.mint == pool.loan_mint @ PrivateScoreError::InvalidTokenMint
     )]
     pub lender_token_account: Account<'info, TokenAccount>,
 
@@ -36,8 +43,14 @@ pub struct Withdraw<'info> {
 pub fn handler(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
     require!(amount > 0, PrivateScoreError::InvalidAmount);
 
+    let clock = Clock::get()?;
+    ctx.accounts.lender_position.record_withdrawal(amount, clock.unix_timestamp)?;
+
     let pool = &ctx.accounts.pool;
-    require!(pool.has_liquidity(amount), PrivateScoreError::InsufficientLiquidity);
+    // `amount` is in position units; after bad debt each unit redeems for less than one token
+    let payout = pool.redeem_value(amount);
+    // Part of the idle liquidity stays behind as a reserve against outstanding borrows
+    require!(payout <= pool.withdrawable_liquidity(), PrivateScoreError::InsufficientLiquidity);
 
     let pool_id_bytes = pool.pool_id.to_le_bytes();
     let seeds = &[b"pool".as_ref(), pool_id_bytes.as_ref(), &[pool.bump]];
@@ -53,12 +66,13 @@ pub fn handler(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
         cpi_accounts,
         signer_seeds,
     );
-    token::transfer(cpi_ctx, amount)?;
+    token::transfer(cpi_ctx, payout)?;
 
     let pool = &mut ctx.accounts.pool;
-    pool.total_deposits = pool.total_deposits.saturating_sub(amount);
-    pool.updated_at = Clock::get()?.unix_timestamp;
+    pool.accrue_borrow_index(clock.unix_timestamp);
+    pool.record_redemption(amount, payout);
+    pool.updated_at = clock.unix_timestamp;
 
-    msg!("Withdrew {} tokens from pool {}", amount, pool.pool_id);
+    msg!("Withdrew {} tokens from pool {}", payout, pool.pool_id);
     Ok(())
 }
\ No newline at end of file
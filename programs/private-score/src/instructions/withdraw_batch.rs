@@ -0,0 +1,74 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! WITHDRAW BATCH - Withdraw from several pools in one transaction
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! `remaining_accounts` holds one group per amount, in order:
+//! [pool, vault, lender_token_account, lender_position] (all writable).
+//! Amounts are position units. Liquidity and position balance are checked per pool as in `withdraw`.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{LenderPosition, LendingPool};
+use crate::errors::PrivateScoreError;
+
+const ACCOUNTS_PER_POOL: usize = 4;
+
+#[derive(Accounts)]
+pub struct WithdrawBatch<'info> {
+    #[account(mut)]
+    pub lender: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, WithdrawBatch<'info>>, amounts: Vec<u64>) -> Result<()> {
+    require!(!amounts.is_empty(), PrivateScoreError::InvalidAmount);
+    require!(
+        ctx.remaining_accounts.len() == amounts.len() * ACCOUNTS_PER_POOL,
+        PrivateScoreError::InvalidAccountState
+    );
+
+    let clock = Clock::get()?;
+    let lender = ctx.accounts.lender.key();
+
+    for (i, amount) in amounts.iter().copied().enumerate() {
+        let accounts = &ctx.remaining_accounts[i * ACCOUNTS_PER_POOL..(i + 1) * ACCOUNTS_PER_POOL];
+        let mut pool: Account<'info, LendingPool> = Account::try_from(&accounts[0])?;
+        let vault: Account<'info, TokenAccount> = Account::try_from(&accounts[1])?;
+        let lender_token_account: Account<'info, TokenAccount> = Account::try_from(&accounts[2])?;
+        let mut position: Account<'info, LenderPosition> = Account::try_from(&accounts[3])?;
+
+        require!(amount > 0, PrivateScoreError::InvalidAmount);
+        require_keys_eq!(vault.key(), pool.vault, PrivateScoreError::InvalidVault);
+        require_keys_eq!(lender_token_account.mint, pool.loan_mint, PrivateScoreError::InvalidTokenMint);
+        require_keys_eq!(position.owner, lender, PrivateScoreError::Unauthorized);
+        require_keys_eq!(position.pool, pool.key(), PrivateScoreError::InvalidAccountState);
+        let payout = pool.redeem_value(amount);
+        require!(payout <= pool.withdrawable_liquidity(), PrivateScoreError::InsufficientLiquidity);
+        position.record_withdrawal(amount, clock.unix_timestamp)?;
+
+        let pool_id_bytes = pool.pool_id.to_le_bytes();
+        let seeds = &[b"pool".as_ref(), pool_id_bytes.as_ref(), &[pool.bump]];
+
+        let cpi_accounts = Transfer {
+            from: vault.to_account_info(),
+            to: lender_token_account.to_account_info(),
+            authority: pool.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, &[seeds]),
+            payout,
+        )?;
+
+        pool.accrue_borrow_index(clock.unix_timestamp);
+        pool.record_redemption(amount, payout);
+        pool.updated_at = clock.unix_timestamp;
+
+        pool.exit(ctx.program_id)?;
+        position.exit(ctx.program_id)?;
+
+        msg!("Withdrew {} tokens from pool {}", payout, pool.pool_id);
+    }
+
+    Ok(())
+}
@@ -18,6 +18,7 @@ pub mod instructions;
 pub mod state;
 
 use instructions::*;
+use state::TierBand;
 
 declare_id!("PSCore1111111111111111111111111111111111111");
 
@@ -25,6 +26,22 @@ declare_id!("PSCore1111111111111111111111111111111111111");
 pub mod privatescore {
     use super::*;
 
+    // ═══════════════════════════════════════════════════════════════════════
+    // GLOBAL CONFIG
+    // ═══════════════════════════════════════════════════════════════════════
+
+    pub fn initialize_global_config(ctx: Context<InitializeGlobalConfig>, compliance_authority: Pubkey, fee_recipient: Pubkey) -> Result<()> {
+        instructions::initialize_global_config::handler(ctx, compliance_authority, fee_recipient)
+    }
+
+    pub fn update_global_config(ctx: Context<UpdateGlobalConfig>, params: GlobalConfigUpdate) -> Result<()> {
+        instructions::update_global_config::handler(ctx, params)
+    }
+
+    pub fn set_global_pause(ctx: Context<SetGlobalPause>, paused: bool) -> Result<()> {
+        instructions::set_global_pause::handler(ctx, paused)
+    }
+
     // ═══════════════════════════════════════════════════════════════════════
     // POOL MANAGEMENT
     // ═══════════════════════════════════════════════════════════════════════
@@ -34,10 +51,93 @@ pub mod privatescore {
         pool_id: u64,
         base_collateral_ratio: u16,
         credit_collateral_ratio: u16,
+        liquidation_threshold: u16,
         interest_rate: u16,
+        min_rate_bps: u16,
+        max_rate_bps: u16,
         min_credit_score: u16,
+        verifier_program: Pubkey,
     ) -> Result<()> {
-        instructions::initialize_pool::handler(ctx, pool_id, base_collateral_ratio, credit_collateral_ratio, interest_rate, min_credit_score)
+        instructions::initialize_pool::handler(
+            ctx, pool_id, base_collateral_ratio, credit_collateral_ratio, liquidation_threshold,
+            interest_rate, min_rate_bps, max_rate_bps, min_credit_score, verifier_program,
+        )
+    }
+
+    pub fn update_pool_params(ctx: Context<UpdatePoolParams>, params: PoolParamsUpdate) -> Result<()> {
+        instructions::update_pool_params::handler(ctx, params)
+    }
+
+    pub fn set_pool_active(ctx: Context<SetPoolActive>, is_active: bool) -> Result<()> {
+        instructions::set_pool_active::handler(ctx, is_active)
+    }
+
+    pub fn set_emergency_mode(ctx: Context<SetEmergencyMode>, enabled: bool) -> Result<()> {
+        instructions::set_emergency_mode::handler(ctx, enabled)
+    }
+
+    pub fn create_admin_multisig(ctx: Context<CreateAdminMultisig>, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        instructions::create_admin_multisig::handler(ctx, signers, threshold)
+    }
+
+    pub fn create_collateral_whitelist(ctx: Context<CreateCollateralWhitelist>) -> Result<()> {
+        instructions::create_collateral_whitelist::handler(ctx)
+    }
+
+    pub fn add_collateral_mint(ctx: Context<AddCollateralMint>) -> Result<()> {
+        instructions::add_collateral_mint::handler(ctx)
+    }
+
+    pub fn remove_collateral_mint(ctx: Context<RemoveCollateralMint>, mint: Pubkey) -> Result<()> {
+        instructions::remove_collateral_mint::handler(ctx, mint)
+    }
+
+    pub fn create_blocklist(ctx: Context<CreateBlocklist>) -> Result<()> {
+        instructions::create_blocklist::handler(ctx)
+    }
+
+    pub fn block_address(ctx: Context<BlockAddress>, address: Pubkey) -> Result<()> {
+        instructions::block_address::handler(ctx, address)
+    }
+
+    pub fn unblock_address(ctx: Context<UnblockAddress>, address: Pubkey) -> Result<()> {
+        instructions::unblock_address::handler(ctx, address)
+    }
+
+    pub fn create_price_feed(ctx: Context<CreatePriceFeed>, updater: Pubkey, price: u64) -> Result<()> {
+        instructions::create_price_feed::handler(ctx, updater, price)
+    }
+
+    pub fn update_price(ctx: Context<UpdatePrice>, price: u64) -> Result<()> {
+        instructions::update_price::handler(ctx, price)
+    }
+
+    pub fn set_price_oracle(ctx: Context<SetPriceOracle>, new_oracle: Pubkey) -> Result<()> {
+        instructions::set_price_oracle::handler(ctx, new_oracle)
+    }
+
+    pub fn add_alt_repay_token(ctx: Context<AddAltRepayToken>) -> Result<()> {
+        instructions::add_alt_repay_token::handler(ctx)
+    }
+
+    pub fn remove_alt_repay_token(ctx: Context<RemoveAltRepayToken>) -> Result<()> {
+        instructions::remove_alt_repay_token::handler(ctx)
+    }
+
+    pub fn create_tier_table(ctx: Context<CreateTierTable>, bands: Vec<TierBand>) -> Result<()> {
+        instructions::create_tier_table::handler(ctx, bands)
+    }
+
+    pub fn create_proof_replay_cache(ctx: Context<CreateProofReplayCache>) -> Result<()> {
+        instructions::create_proof_replay_cache::handler(ctx)
+    }
+
+    pub fn update_tier_table(ctx: Context<UpdateTierTable>, bands: Vec<TierBand>) -> Result<()> {
+        instructions::update_tier_table::handler(ctx, bands)
+    }
+
+    pub fn open_lender_position(ctx: Context<OpenLenderPosition>) -> Result<()> {
+        instructions::open_lender_position::handler(ctx)
     }
 
     pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
@@ -48,47 +148,270 @@ pub mod privatescore {
         instructions::withdraw::handler(ctx, amount)
     }
 
+    pub fn emergency_withdraw(ctx: Context<EmergencyWithdraw>) -> Result<()> {
+        instructions::emergency_withdraw::handler(ctx)
+    }
+
+    pub fn deposit_batch<'info>(ctx: Context<'_, '_, 'info, 'info, DepositBatch<'info>>, amounts: Vec<u64>) -> Result<()> {
+        instructions::deposit_batch::handler(ctx, amounts)
+    }
+
+    pub fn withdraw_batch<'info>(ctx: Context<'_, '_, 'info, 'info, WithdrawBatch<'info>>, amounts: Vec<u64>) -> Result<()> {
+        instructions::withdraw_batch::handler(ctx, amounts)
+    }
+
+    pub fn fund_insurance(ctx: Context<FundInsurance>, amount: u64) -> Result<()> {
+        instructions::fund_insurance::handler(ctx, amount)
+    }
+
+    pub fn reinvest_reserves(ctx: Context<ReinvestReserves>, amount: u64) -> Result<()> {
+        instructions::reinvest_reserves::handler(ctx, amount)
+    }
+
+    pub fn open_pool_snapshots(ctx: Context<OpenPoolSnapshots>) -> Result<()> {
+        instructions::open_pool_snapshots::handler(ctx)
+    }
+
+    pub fn snapshot_pool(ctx: Context<SnapshotPool>) -> Result<()> {
+        instructions::snapshot_pool::handler(ctx)
+    }
+
     // ═══════════════════════════════════════════════════════════════════════
     // CREDIT MANAGEMENT
     // ═══════════════════════════════════════════════════════════════════════
 
-    pub fn register_credit(ctx: Context<RegisterCredit>, commitment: [u8; 32], tier: u8) -> Result<()> {
-        instructions::register_credit::handler(ctx, commitment, tier)
+    pub fn register_credit(ctx: Context<RegisterCredit>, commitment: [u8; 32], tier: u8, expiry_duration: i64) -> Result<()> {
+        instructions::register_credit::handler(ctx, commitment, tier, expiry_duration)
+    }
+
+    pub fn register_credit_batch<'info>(ctx: Context<'_, '_, 'info, 'info, RegisterCreditBatch<'info>>, entries: Vec<CreditRegistration>) -> Result<()> {
+        instructions::register_credit_batch::handler(ctx, entries)
     }
 
     pub fn update_credit(ctx: Context<UpdateCredit>, new_commitment: [u8; 32], new_tier: u8) -> Result<()> {
         instructions::update_credit::handler(ctx, new_commitment, new_tier)
     }
 
+    pub fn freeze_credit_record(ctx: Context<FreezeCreditRecord>) -> Result<()> {
+        instructions::freeze_credit_record::handler(ctx)
+    }
+
+    pub fn unfreeze_credit_record(ctx: Context<UnfreezeCreditRecord>) -> Result<()> {
+        instructions::unfreeze_credit_record::handler(ctx)
+    }
+
+    pub fn set_recovery_authority(ctx: Context<SetRecoveryAuthority>, recovery_authority: Pubkey) -> Result<()> {
+        instructions::set_recovery_authority::handler(ctx, recovery_authority)
+    }
+
+    pub fn set_borrow_delegate(ctx: Context<SetBorrowDelegate>, borrow_delegate: Pubkey) -> Result<()> {
+        instructions::set_borrow_delegate::handler(ctx, borrow_delegate)
+    }
+
+    pub fn set_beneficiary(ctx: Context<SetBeneficiary>, beneficiary: Pubkey) -> Result<()> {
+        instructions::set_beneficiary::handler(ctx, beneficiary)
+    }
+
+    pub fn set_auto_renew(ctx: Context<SetAutoRenew>, enabled: bool) -> Result<()> {
+        instructions::set_auto_renew::handler(ctx, enabled)
+    }
+
+    pub fn initiate_recovery(ctx: Context<InitiateRecovery>, new_owner: Pubkey) -> Result<()> {
+        instructions::initiate_recovery::handler(ctx, new_owner)
+    }
+
+    pub fn cancel_recovery(ctx: Context<CancelRecovery>) -> Result<()> {
+        instructions::cancel_recovery::handler(ctx)
+    }
+
+    pub fn complete_recovery(ctx: Context<CompleteRecovery>) -> Result<()> {
+        instructions::complete_recovery::handler(ctx)
+    }
+
     // ═══════════════════════════════════════════════════════════════════════
     // BORROWING OPERATIONS
     // ═══════════════════════════════════════════════════════════════════════
 
-    pub fn verify_and_borrow(ctx: Context<VerifyAndBorrow>, amount: u64, proof: Vec<u8>, public_inputs: Vec<u8>) -> Result<()> {
-        instructions::verify_and_borrow::handler(ctx, amount, proof, public_inputs)
+    pub fn submit_proof(ctx: Context<SubmitProof>, proof: Vec<u8>, public_inputs: Vec<u8>) -> Result<()> {
+        instructions::submit_proof::handler(ctx, proof, public_inputs)
+    }
+
+    pub fn verify_and_borrow(
+        ctx: Context<VerifyAndBorrow>,
+        amount: u64,
+        max_collateral: u64,
+        cosigner_collateral: u64,
+        duration: i64,
+        num_installments: u16,
+        installment_interval: i64,
+        proof: Vec<u8>,
+        public_inputs: Vec<u8>,
+    ) -> Result<()> {
+        instructions::verify_and_borrow::handler(ctx, amount, max_collateral, cosigner_collateral, duration, num_installments, installment_interval, proof, public_inputs)
+    }
+
+    pub fn borrow_standard(
+        ctx: Context<BorrowStandard>,
+        amount: u64,
+        max_collateral: u64,
+        duration: i64,
+        num_installments: u16,
+        installment_interval: i64,
+    ) -> Result<()> {
+        instructions::borrow_standard::handler(ctx, amount, max_collateral, duration, num_installments, installment_interval)
+    }
+
+    pub fn borrow_with_receipt(
+        ctx: Context<BorrowWithReceipt>,
+        amount: u64,
+        max_collateral: u64,
+        duration: i64,
+        num_installments: u16,
+        installment_interval: i64,
+    ) -> Result<()> {
+        instructions::borrow_with_receipt::handler(ctx, amount, max_collateral, duration, num_installments, installment_interval)
+    }
+
+    pub fn preview_terms(ctx: Context<PreviewTerms>, tier: u8, amount: u64) -> Result<TermsPreview> {
+        instructions::preview_terms::handler(ctx, tier, amount)
     }
 
-    pub fn borrow_standard(ctx: Context<BorrowStandard>, amount: u64) -> Result<()> {
-        instructions::borrow_standard::handler(ctx, amount)
+    pub fn quote_borrow(ctx: Context<QuoteBorrow>, amount: u64, is_credit_verified: bool) -> Result<BorrowQuote> {
+        instructions::quote_borrow::handler(ctx, amount, is_credit_verified)
+    }
+
+    pub fn quote_rates(ctx: Context<QuoteRates>) -> Result<RateQuote> {
+        instructions::quote_rates::handler(ctx)
+    }
+
+    pub fn is_liquidatable(ctx: Context<IsLiquidatable>) -> Result<LiquidationCheck> {
+        instructions::is_liquidatable::handler(ctx)
     }
 
     pub fn repay(ctx: Context<Repay>, amount: u64) -> Result<()> {
         instructions::repay::handler(ctx, amount)
     }
 
+    pub fn release_upgraded_collateral(
+        ctx: Context<ReleaseUpgradedCollateral>,
+        proof: Vec<u8>,
+        public_inputs: Vec<u8>,
+    ) -> Result<()> {
+        instructions::release_upgraded_collateral::handler(ctx, proof, public_inputs)
+    }
+
+    pub fn repay_with_collateral<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RepayWithCollateral<'info>>,
+        max_collateral_in: u64,
+        min_amount_out: u64,
+        swap_data: Vec<u8>,
+    ) -> Result<()> {
+        instructions::repay_with_collateral::handler(ctx, max_collateral_in, min_amount_out, swap_data)
+    }
+
+    pub fn repay_with_alt_token<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RepayWithAltToken<'info>>,
+        max_amount_in: u64,
+        min_amount_out: u64,
+        adapter_data: Vec<u8>,
+    ) -> Result<()> {
+        instructions::repay_with_alt_token::handler(ctx, max_amount_in, min_amount_out, adapter_data)
+    }
+
+    pub fn accrue_interest(ctx: Context<AccrueInterest>) -> Result<()> {
+        instructions::accrue_interest::handler(ctx)
+    }
+
+    pub fn mark_delinquent(ctx: Context<MarkDelinquent>) -> Result<()> {
+        instructions::mark_delinquent::handler(ctx)
+    }
+
+    pub fn start_liquidation_auction(ctx: Context<StartLiquidationAuction>) -> Result<()> {
+        instructions::start_liquidation_auction::handler(ctx)
+    }
+
+    pub fn cancel_liquidation_auction(ctx: Context<CancelLiquidationAuction>) -> Result<()> {
+        instructions::cancel_liquidation_auction::handler(ctx)
+    }
+
     pub fn liquidate(ctx: Context<Liquidate>) -> Result<()> {
         instructions::liquidate::handler(ctx)
     }
 
+    pub fn liquidate_with_swap<'info>(
+        ctx: Context<'_, '_, 'info, 'info, LiquidateWithSwap<'info>>,
+        max_collateral_in: u64,
+        min_amount_out: u64,
+        swap_data: Vec<u8>,
+    ) -> Result<()> {
+        instructions::liquidate_with_swap::handler(ctx, max_collateral_in, min_amount_out, swap_data)
+    }
+
+    pub fn sweep_dust(ctx: Context<SweepDust>) -> Result<()> {
+        instructions::sweep_dust::handler(ctx)
+    }
+
+    pub fn migrate_collateral_mint(ctx: Context<MigrateCollateralMint>) -> Result<()> {
+        instructions::migrate_collateral_mint::handler(ctx)
+    }
+
+    pub fn register_mint_successor(ctx: Context<RegisterMintSuccessor>, old_mint: Pubkey) -> Result<()> {
+        instructions::register_mint_successor::handler(ctx, old_mint)
+    }
+
+    pub fn migrate_loan(ctx: Context<MigrateLoan>) -> Result<()> {
+        instructions::migrate_loan::handler(ctx)
+    }
+
     // ═══════════════════════════════════════════════════════════════════════
     // RANGE PROTOCOL - SELECTIVE DISCLOSURE
     // ═══════════════════════════════════════════════════════════════════════
 
-    pub fn grant_viewing_access(ctx: Context<GrantViewingAccess>, viewer: Pubkey, access_level: u8, expiry: i64) -> Result<()> {
-        instructions::grant_viewing_access::handler(ctx, viewer, access_level, expiry)
+    pub fn grant_viewing_access(ctx: Context<GrantViewingAccess>, viewer: Pubkey, access_level: u8, expiry: i64, min_access_interval: i64, active_from: i64) -> Result<()> {
+        instructions::grant_viewing_access::handler(ctx, viewer, access_level, expiry, min_access_interval, active_from)
+    }
+
+    pub fn disclose_credit(ctx: Context<DiscloseCredit>, proof: Vec<u8>, public_inputs: Vec<u8>) -> Result<()> {
+        instructions::disclose_credit::handler(ctx, proof, public_inputs)
+    }
+
+    pub fn disclose_predicate(ctx: Context<DisclosePredicate>, required_score: u16, proof: Vec<u8>, public_inputs: Vec<u8>) -> Result<()> {
+        instructions::disclose_predicate::handler(ctx, required_score, proof, public_inputs)
     }
 
     pub fn revoke_viewing_access(ctx: Context<RevokeViewingAccess>) -> Result<()> {
         instructions::revoke_viewing_access::handler(ctx)
     }
+
+    pub fn revoke_all_viewing_access<'info>(ctx: Context<'_, '_, 'info, 'info, RevokeAllViewingAccess<'info>>) -> Result<()> {
+        instructions::revoke_all_viewing_access::handler(ctx)
+    }
+
+    pub fn export_credit_history(ctx: Context<ExportCreditHistory>) -> Result<()> {
+        instructions::export_credit_history::handler(ctx)
+    }
+
+    pub fn set_max_viewing_keys(ctx: Context<SetMaxViewingKeys>, max_viewing_keys: u32) -> Result<()> {
+        instructions::set_max_viewing_keys::handler(ctx, max_viewing_keys)
+    }
+
+    pub fn set_expiry_warning_window(ctx: Context<SetExpiryWarningWindow>, expiry_warning_window: i64) -> Result<()> {
+        instructions::set_expiry_warning_window::handler(ctx, expiry_warning_window)
+    }
+
+    pub fn pause_disclosures(ctx: Context<PauseDisclosures>) -> Result<()> {
+        instructions::pause_disclosures::handler(ctx)
+    }
+
+    pub fn resume_disclosures(ctx: Context<ResumeDisclosures>) -> Result<()> {
+        instructions::resume_disclosures::handler(ctx)
+    }
+
+    pub fn audit_commitment(ctx: Context<AuditCommitment>, score: u16, salt: [u8; 32]) -> Result<()> {
+        instructions::audit_commitment::handler(ctx, score, salt)
+    }
+
+    pub fn self_disclose(ctx: Context<SelfDisclose>) -> Result<()> {
+        instructions::self_disclose::handler(ctx)
+    }
 }
\ No newline at end of file
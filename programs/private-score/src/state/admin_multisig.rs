@@ -0,0 +1,39 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! ADMIN MULTISIG - m-of-n approval for sensitive pool admin actions
+//! ═══════════════════════════════════════════════════════════════════════════
+
+use anchor_lang::prelude::*;
+use crate::errors::PrivateScoreError;
+
+#[account]
+#[derive(Default)]
+pub struct AdminMultisig {
+    pub pool: Pubkey,
+    pub signers: [Pubkey; 5],
+    pub num_signers: u8,
+    pub threshold: u8,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl AdminMultisig {
+    pub const LEN: usize = 8 + 32 + 32 * 5 + 1 + 1 + 8 + 1;
+    pub const MAX_SIGNERS: usize = 5;
+    pub const SEED_PREFIX: &'static [u8] = b"admin_multisig";
+
+    pub fn is_member(&self, key: &Pubkey) -> bool {
+        self.signers[..self.num_signers as usize].contains(key)
+    }
+
+    /// Count distinct members that signed among `accounts` and require the threshold
+    pub fn verify_approvals(&self, accounts: &[AccountInfo]) -> Result<()> {
+        let mut approved: Vec<Pubkey> = Vec::with_capacity(self.num_signers as usize);
+        for account in accounts.iter() {
+            if account.is_signer && self.is_member(account.key) && !approved.contains(account.key) {
+                approved.push(*account.key);
+            }
+        }
+        require!(approved.len() >= self.threshold as usize, PrivateScoreError::Unauthorized);
+        Ok(())
+    }
+}
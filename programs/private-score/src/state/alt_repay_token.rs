@@ -0,0 +1,24 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! ALT REPAY TOKEN - Alternate stablecoin a pool accepts for repayment
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! The adapter program converts the alternate token into the pool's loan mint,
+//! either by swapping or by 1:1 redemption. Only what actually lands in the
+//! pool vault is credited against the debt.
+
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct AltRepayToken {
+    pub pool: Pubkey,
+    pub mint: Pubkey,
+    pub adapter_program: Pubkey,    // Converts `mint` into the pool's loan mint
+    pub added_at: i64,
+    pub bump: u8,
+}
+
+impl AltRepayToken {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 1;
+    pub const SEED_PREFIX: &'static [u8] = b"alt_repay_token";
+}
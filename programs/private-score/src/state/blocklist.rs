@@ -0,0 +1,50 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! BLOCKLIST - Admin-managed set of addresses barred from a pool
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! Once a pool has a blocklist, borrows and deposits must pass it so the
+//! check cannot be skipped by omitting the account.
+
+use anchor_lang::prelude::*;
+use crate::errors::PrivateScoreError;
+
+#[account]
+#[derive(Default)]
+pub struct Blocklist {
+    pub pool: Pubkey,
+    pub addresses: [Pubkey; 32],
+    pub num_addresses: u8,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl Blocklist {
+    pub const LEN: usize = 8 + 32 + 32 * 32 + 1 + 8 + 1;
+    pub const MAX_ADDRESSES: usize = 32;
+    pub const SEED_PREFIX: &'static [u8] = b"blocklist";
+
+    pub fn contains(&self, address: &Pubkey) -> bool {
+        self.addresses[..self.num_addresses as usize].contains(address)
+    }
+
+    pub fn add(&mut self, address: Pubkey) -> Result<()> {
+        require!(!self.contains(&address), PrivateScoreError::AlreadyInitialized);
+        require!((self.num_addresses as usize) < Self::MAX_ADDRESSES, PrivateScoreError::InvalidAccountState);
+        self.addresses[self.num_addresses as usize] = address;
+        self.num_addresses += 1;
+        Ok(())
+    }
+
+    /// Swap-remove; order of the list is not meaningful
+    pub fn remove(&mut self, address: &Pubkey) -> Result<()> {
+        let count = self.num_addresses as usize;
+        let index = self.addresses[..count]
+            .iter()
+            .position(|a| a == address)
+            .ok_or(PrivateScoreError::InvalidAccountState)?;
+        self.addresses[index] = self.addresses[count - 1];
+        self.addresses[count - 1] = Pubkey::default();
+        self.num_addresses -= 1;
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! COLLATERAL WHITELIST - Admin-managed set of extra collateral mints per pool
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! The pool's own `collateral_mint` is always accepted. Whitelisted mints must
+//! share its decimals, since collateral is valued 1:1 per whole token.
+
+use anchor_lang::prelude::*;
+use crate::errors::PrivateScoreError;
+
+#[account]
+#[derive(Default)]
+pub struct CollateralWhitelist {
+    pub pool: Pubkey,
+    pub mints: [Pubkey; 16],
+    pub num_mints: u8,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl CollateralWhitelist {
+    pub const LEN: usize = 8 + 32 + 32 * 16 + 1 + 8 + 1;
+    pub const MAX_MINTS: usize = 16;
+    pub const SEED_PREFIX: &'static [u8] = b"collateral_whitelist";
+
+    pub fn contains(&self, mint: &Pubkey) -> bool {
+        self.mints[..self.num_mints as usize].contains(mint)
+    }
+
+    pub fn add(&mut self, mint: Pubkey) -> Result<()> {
+        require!(!self.contains(&mint), PrivateScoreError::AlreadyInitialized);
+        require!((self.num_mints as usize) < Self::MAX_MINTS, PrivateScoreError::InvalidAccountState);
+        self.mints[self.num_mints as usize] = mint;
+        self.num_mints += 1;
+        Ok(())
+    }
+
+    /// Swap-remove; order of the list is not meaningful
+    pub fn remove(&mut self, mint: &Pubkey) -> Result<()> {
+        let count = self.num_mints as usize;
+        let index = self.mints[..count]
+            .iter()
+            .position(|m| m == mint)
+            .ok_or(PrivateScoreError::InvalidTokenMint)?;
+        self.mints[index] = self.mints[count - 1];
+        self.mints[count - 1] = Pubkey::default();
+        self.num_mints -= 1;
+        Ok(())
+    }
+}
@@ -3,6 +3,8 @@
 //! ═══════════════════════════════════════════════════════════════════════════
 
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::poseidon::{hashv, Endianness, Parameters};
+use crate::errors::PrivateScoreError;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
 pub enum CreditTier {
@@ -27,6 +29,14 @@ impl CreditTier {
         }
     }
 
+    /// Tier a score falls in under the default bands above
+    pub fn from_score(score: u16) -> Self {
+        [CreditTier::Excellent, CreditTier::VeryGood, CreditTier::Good, CreditTier::Fair, CreditTier::Poor]
+            .into_iter()
+            .find(|tier| score >= tier.min_score())
+            .unwrap_or(CreditTier::Unknown)
+    }
+
     pub fn from_u8(value: u8) -> Self {
         match value {
             1 => CreditTier::Poor,
@@ -38,15 +48,45 @@ impl CreditTier {
         }
     }
 
+    /// Like `from_u8`, but out-of-range values are an error instead of Unknown
+    pub fn try_from_u8(value: u8) -> Result<Self> {
+        require!(value <= 5, PrivateScoreError::InvalidCreditScore);
+        Ok(Self::from_u8(value))
+    }
+
+    pub fn to_u8(&self) -> u8 {
+        match self {
+            CreditTier::Unknown => 0,
+            CreditTier::Poor => 1,
+            CreditTier::Fair => 2,
+            CreditTier::Good => 3,
+            CreditTier::VeryGood => 4,
+            CreditTier::Excellent => 5,
+        }
+    }
+
     pub fn qualifies_for_reduced_collateral(&self) -> bool {
         matches!(self, CreditTier::Good | CreditTier::VeryGood | CreditTier::Excellent)
     }
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct TierChange {
+    pub old_tier: CreditTier,
+    pub new_tier: CreditTier,
+    pub changed_at: i64,
+}
+
+impl TierChange {
+    pub const LEN: usize = 1 + 1 + 8;
+}
+
 #[account]
 #[derive(Default)]
 pub struct CreditRecord {
     pub owner: Pubkey,
+    pub borrow_delegate: Pubkey,          // May call verify_and_borrow for the owner (Default = none)
+    pub beneficiary: Pubkey,              // Whose credit is measured; credit loans are attributed to it (Default = owner)
     pub commitment: [u8; 32],       // Hash(score || salt)
     pub tier: CreditTier,
     pub nonce: u64,
@@ -59,24 +99,64 @@ pub struct CreditRecord {
     pub total_repaid: u64,
     pub on_time_repayments: u32,
     pub late_repayments: u32,
+    pub active_viewing_keys: u32,
+    pub max_viewing_keys: u32,            // Grant limit (0 = DEFAULT_MAX_VIEWING_KEYS)
     pub is_active: bool,
+    pub frozen: bool,
     pub disclosure_enabled: bool,
+    pub disclosure_paused: bool,          // Owner hold on all viewing keys without revoking them
     pub is_compressed: bool,
+    pub auto_renew: bool,                 // Each fresh proof in verify_and_borrow extends expires_at
     pub merkle_tree: Pubkey,
+    pub session_proof_hash: [u8; 32],
+    pub session_expires_at: i64,
+    pub session_borrows_remaining: u8,
+    pub session_pool: Pubkey,             // Pool the session's proof was verified for
+    pub recovery_authority: Pubkey,       // Default = recovery disabled
+    pub pending_owner: Pubkey,            // Set while a recovery is in its timelock
+    pub recovery_initiated_at: i64,
+    pub liquidation_cooldown_until: i64,  // No new loans before this; set when a loan is liquidated
+    pub tier_history: [TierChange; 4],    // Most recent first; unused slots have changed_at = 0
     pub _reserved: [u8; 32],
     pub bump: u8,
 }
 
 impl CreditRecord {
-    pub const LEN: usize = 8 + 32 + 32 + 1 + 8 + 8 + 8 + 8 + 4 + 4 + 8 + 8 + 4 + 4 + 1 + 1 + 1 + 32 + 32 + 1;
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 32 + 1 + 8 + 8 + 8 + 8 + 4 + 4 + 8 + 8 + 4 + 4 + 4 + 4 + 1 + 1 + 1 + 1 + 1 + 1 + 32 + 32 + 8 + 1 + 32 + 32 + 32 + 8 + 8 + TierChange::LEN * 4 + 32 + 1;
     pub const DEFAULT_EXPIRY_DURATION: i64 = 30 * 24 * 60 * 60;
+    pub const MIN_EXPIRY_DURATION: i64 = 24 * 60 * 60;
+    pub const MAX_EXPIRY_DURATION: i64 = 90 * 24 * 60 * 60;
+    pub const SESSION_DURATION: i64 = 10 * 60;
+    pub const SESSION_MAX_BORROWS: u8 = 3;
+    pub const RECOVERY_TIMELOCK: i64 = 7 * 24 * 60 * 60;
+    pub const DEFAULT_MAX_VIEWING_KEYS: u32 = 16;
+    pub const MAX_VIEWING_KEYS_LIMIT: u32 = 64;
 
     pub fn is_expired(&self, current_time: i64) -> bool {
         self.expires_at > 0 && current_time > self.expires_at
     }
 
     pub fn can_borrow(&self, current_time: i64) -> bool {
-        self.is_active && !self.is_expired(current_time)
+        self.is_active && !self.frozen && !self.is_expired(current_time)
+    }
+
+    /// Push expiry out by the default duration when the owner opted into auto-renewal
+    pub fn renew_if_enabled(&mut self, current_time: i64) {
+        if self.auto_renew {
+            self.expires_at = self.expires_at.max(current_time + Self::DEFAULT_EXPIRY_DURATION);
+        }
+    }
+
+    pub fn disclosures_allowed(&self) -> bool {
+        self.disclosure_enabled && !self.disclosure_paused
+    }
+
+    pub fn viewing_key_limit(&self) -> u32 {
+        if self.max_viewing_keys == 0 { Self::DEFAULT_MAX_VIEWING_KEYS } else { self.max_viewing_keys }
+    }
+
+    pub fn can_grant_viewing_key(&self) -> bool {
+        self.active_viewing_keys < self.viewing_key_limit()
     }
 
     pub fn increment_nonce(&mut self) {
@@ -95,6 +175,94 @@ impl CreditRecord {
         self.updated_at = current_time;
         self.expires_at = current_time + Self::DEFAULT_EXPIRY_DURATION;
         self.increment_nonce();
+        self.close_session();
+    }
+
+    /// Credit session: one verified proof unlocks a few borrows in a short window,
+    /// in the pool the proof was verified for only
+    pub fn has_active_session(&self, pool: &Pubkey, current_time: i64) -> bool {
+        self.session_borrows_remaining > 0
+            && current_time <= self.session_expires_at
+            && self.session_pool == *pool
+    }
+
+    /// `borrows_used` is 1 when the verifying instruction itself borrowed
+    pub fn open_session(&mut self, pool: Pubkey, proof_hash: [u8; 32], borrows_used: u8, current_time: i64) {
+        self.session_pool = pool;
+        self.session_proof_hash = proof_hash;
+        self.session_expires_at = current_time + Self::SESSION_DURATION;
+        self.session_borrows_remaining = Self::SESSION_MAX_BORROWS.saturating_sub(borrows_used);
+    }
+
+    pub fn consume_session(&mut self) {
+        self.session_borrows_remaining = self.session_borrows_remaining.saturating_sub(1);
+    }
+
+    pub fn close_session(&mut self) {
+        self.session_borrows_remaining = 0;
+        self.session_expires_at = 0;
+    }
+
+    /// Poseidon(score || salt) over BN254. Only commitments built with the same
+    /// hash can be opened on-chain; the Noir circuits must commit with Poseidon too.
+    pub fn compute_commitment(score: u16, salt: &[u8; 32]) -> Result<[u8; 32]> {
+        let mut score_bytes = [0u8; 32];
+        score_bytes[30..].copy_from_slice(&score.to_be_bytes());
+        let hash = hashv(Parameters::Bn254X5, Endianness::BigEndian, &[&score_bytes, salt])
+            .map_err(|_| error!(PrivateScoreError::InvalidCommitment))?;
+        Ok(hash.to_bytes())
+    }
+
+    /// Check a revealed (score, salt) opens the stored commitment
+    pub fn verify_opening(&self, score: u16, salt: &[u8; 32]) -> Result<()> {
+        let computed = Self::compute_commitment(score, salt)?;
+        require!(computed == self.commitment, PrivateScoreError::InvalidCommitment);
+        Ok(())
+    }
+
+    /// Push a tier transition onto the rolling history, dropping the oldest
+    pub fn record_tier_change(&mut self, new_tier: CreditTier, current_time: i64) {
+        self.tier_history.rotate_right(1);
+        self.tier_history[0] = TierChange {
+            old_tier: self.tier,
+            new_tier,
+            changed_at: current_time,
+        };
+    }
+
+    pub fn has_pending_recovery(&self) -> bool {
+        self.recovery_initiated_at > 0
+    }
+
+    pub fn recovery_executable_at(&self) -> i64 {
+        self.recovery_initiated_at.saturating_add(Self::RECOVERY_TIMELOCK)
+    }
+
+    pub fn clear_recovery(&mut self) {
+        self.pending_owner = Pubkey::default();
+        self.recovery_initiated_at = 0;
+    }
+
+    /// The owner, the beneficiary, or the borrow delegate the owner appointed
+    pub fn can_borrow_as(&self, signer: &Pubkey) -> bool {
+        *signer == self.owner
+            || *signer == self.borrower_key()
+            || (self.borrow_delegate != Pubkey::default() && *signer == self.borrow_delegate)
+    }
+
+    /// Account credit loans are attributed to; the owner only manages the record
+    pub fn borrower_key(&self) -> Pubkey {
+        if self.beneficiary == Pubkey::default() { self.owner } else { self.beneficiary }
+    }
+
+    /// Extend (never shorten) the re-borrow cooldown after a liquidation
+    pub fn start_liquidation_cooldown(&mut self, cooldown: i64, current_time: i64) {
+        if cooldown <= 0 { return; }
+        self.liquidation_cooldown_until = self.liquidation_cooldown_until.max(current_time.saturating_add(cooldown));
+    }
+
+    pub fn in_liquidation_cooldown(&self, current_time: i64) -> bool {
+        current_time < self.liquidation_cooldown_until
     }
 
     pub fn record_loan(&mut self, amount: u64) {
@@ -110,4 +278,100 @@ impl CreditRecord {
             self.late_repayments = self.late_repayments.saturating_add(1);
         }
     }
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct TierChanged {
+    pub credit_record: Pubkey,
+    pub owner: Pubkey,
+    pub old_tier: CreditTier,
+    pub new_tier: CreditTier,
+    pub timestamp: i64,
+}
+
+/// An audited opening maps to a different tier than the record stores
+#[event]
+pub struct TierMismatchDetected {
+    pub credit_record: Pubkey,
+    pub owner: Pubkey,
+    pub auditor: Pubkey,
+    pub stored_tier: CreditTier,
+    pub opened_tier: CreditTier,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RecoveryInitiated {
+    pub credit_record: Pubkey,
+    pub recovery_authority: Pubkey,
+    pub current_owner: Pubkey,
+    pub pending_owner: Pubkey,
+    pub executable_at: i64,
+}
+
+#[event]
+pub struct RecoveryCancelled {
+    pub credit_record: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreditRecordRecovered {
+    pub old_record: Pubkey,
+    pub new_record: Pubkey,
+    pub old_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Portable repayment-history attestation; emitted only by this program
+#[event]
+pub struct CreditHistoryExported {
+    pub credit_record: Pubkey,
+    pub owner: Pubkey,
+    pub commitment: [u8; 32],
+    pub tier: CreditTier,
+    pub loans_taken: u32,
+    pub on_time_repayments: u32,
+    pub late_repayments: u32,
+    pub repayment_ratio: u16,
+    pub total_borrowed: u64,
+    pub total_repaid: u64,
+    pub nonce: u64,
+    pub exported_at: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn session_is_bound_to_its_pool_and_window() {
+        let pool = Pubkey::new_unique();
+        let mut record = CreditRecord::default();
+        record.open_session(pool, [7u8; 32], 1, 100);
+        assert_eq!(record.session_borrows_remaining, CreditRecord::SESSION_MAX_BORROWS - 1);
+        assert!(record.has_active_session(&pool, 100 + CreditRecord::SESSION_DURATION));
+        assert!(!record.has_active_session(&pool, 101 + CreditRecord::SESSION_DURATION));
+        assert!(!record.has_active_session(&Pubkey::new_unique(), 100));
+    }
+
+    #[test]
+    fn session_ends_when_its_borrows_run_out() {
+        let pool = Pubkey::new_unique();
+        let mut record = CreditRecord::default();
+        record.open_session(pool, [7u8; 32], CreditRecord::SESSION_MAX_BORROWS - 1, 100);
+        assert!(record.has_active_session(&pool, 100));
+        record.consume_session();
+        assert!(!record.has_active_session(&pool, 100));
+    }
+
+    #[test]
+    fn try_from_u8_rejects_out_of_range_tiers() {
+        assert!(matches!(CreditTier::try_from_u8(0), Ok(CreditTier::Unknown)));
+        assert!(matches!(CreditTier::try_from_u8(5), Ok(CreditTier::Excellent)));
+        assert!(CreditTier::try_from_u8(6).is_err());
+        assert!(CreditTier::try_from_u8(u8::MAX).is_err());
+    }
+}
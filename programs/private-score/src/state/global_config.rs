@@ -0,0 +1,35 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! GLOBAL CONFIG - Program-wide parameters (singleton PDA)
+//! ═══════════════════════════════════════════════════════════════════════════
+
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct GlobalConfig {
+    pub super_admin: Pubkey,
+    pub compliance_authority: Pubkey,
+    pub score_provider: Pubkey,         // May register credit records in bulk (Default = disabled)
+    pub fee_recipient: Pubkey,
+    pub max_oracle_staleness: i64,      // Seconds before a price is considered stale
+    pub swap_programs: [Pubkey; 4],     // DEX programs repay_with_collateral and liquidate_with_swap may CPI into
+    pub global_paused: bool,            // Halts new borrows in every pool
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub _reserved: [u8; 64],
+    pub bump: u8,
+}
+
+impl GlobalConfig {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 32 + 8 + 128 + 1 + 8 + 8 + 64 + 1;
+    pub const SEED: &'static [u8] = b"global_config";
+    pub const DEFAULT_MAX_ORACLE_STALENESS: i64 = 60;
+
+    pub fn is_score_provider(&self, key: &Pubkey) -> bool {
+        self.score_provider != Pubkey::default() && self.score_provider == *key
+    }
+
+    pub fn is_swap_program(&self, program_id: &Pubkey) -> bool {
+        *program_id != Pubkey::default() && self.swap_programs.contains(program_id)
+    }
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::errors::PrivateScoreError;
 
 /// Lender's position in a lending pool
 #[account]
@@ -6,7 +7,7 @@ use anchor_lang::prelude::*;
 pub struct LenderPosition {
     pub owner: Pubkey,
     pub pool: Pubkey,
-    pub deposited_amount: u64,
+    pub deposited_amount: u64,          // Position units; see LendingPool::redeem_value
     pub pool_share_bps: u64,
     pub interest_earned: u64,
     pub interest_withdrawn: u64,
@@ -18,4 +19,16 @@ pub struct LenderPosition {
 impl LenderPosition {
     pub const SIZE: usize = 8 + 32*2 + 8*6 + 1 + 32;
     pub const SEED_PREFIX: &'static [u8] = b"lender_position";
+
+    pub fn record_deposit(&mut self, amount: u64, current_time: i64) {
+        self.deposited_amount = self.deposited_amount.saturating_add(amount);
+        self.last_update = current_time;
+    }
+
+    pub fn record_withdrawal(&mut self, amount: u64, current_time: i64) -> Result<()> {
+        require!(amount <= self.deposited_amount, PrivateScoreError::InsufficientLiquidity);
+        self.deposited_amount -= amount;
+        self.last_update = current_time;
+        Ok(())
+    }
 }
\ No newline at end of file
@@ -3,43 +3,422 @@
 //! ═══════════════════════════════════════════════════════════════════════════
 
 use anchor_lang::prelude::*;
+use crate::state::{AdminMultisig, Blocklist, CollateralWhitelist, CreditTier, PriceFeed, ProofReplayCache, TierTable};
+use crate::errors::PrivateScoreError;
+
+/// Which collateral source `liquidate` draws down first
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SeizureOrder {
+    #[default]
+    BorrowerFirst,
+    CosignerFirst,
+}
+
+impl SeizureOrder {
+    /// Split a seizure of `total` into (from_borrower, from_cosigner)
+    pub fn split(&self, total: u64, borrower_amount: u64, cosigner_amount: u64) -> (u64, u64) {
+        match self {
+            SeizureOrder::BorrowerFirst => {
+                let from_borrower = total.min(borrower_amount);
+                (from_borrower, (total - from_borrower).min(cosigner_amount))
+            }
+            SeizureOrder::CosignerFirst => {
+                let from_cosigner = total.min(cosigner_amount);
+                ((total - from_cosigner).min(borrower_amount), from_cosigner)
+            }
+        }
+    }
+}
 
 #[account]
 #[derive(Default)]
 pub struct LendingPool {
     pub authority: Pubkey,
     pub pool_id: u64,
-    pub token_mint: Pubkey,
+    pub loan_mint: Pubkey,
+    pub collateral_mint: Pubkey,
     pub vault: Pubkey,
+    pub admin_multisig: Pubkey,          // Default = single-key authority
+    pub blocklist: Pubkey,               // Default = no blocklist
+    pub tier_table: Pubkey,              // Custom credit bands (Default = tier stored on the credit record)
+    pub price_oracle: Pubkey,            // PriceFeed valuing collateral (Default = 1:1 per whole token)
+    pub verifier_program: Pubkey,        // Sunspot verifier accepted by verify_and_borrow
+    pub proof_replay_cache: Pubkey,      // Default = no replay cache
+    pub loan_decimals: u8,
+    pub collateral_decimals: u8,
     pub base_collateral_ratio: u16,      // 15000 = 150%
     pub credit_collateral_ratio: u16,    // 12000 = 120%
     pub liquidation_threshold: u16,      // 11000 = 110%
-    pub interest_rate: u16,              // 500 = 5% APY
+    pub min_opening_health_factor: u16,  // 11500 = new loans need 115% collateral value
+    pub interest_rate: u16,              // 500 = 5% APY at zero utilization
+    pub min_rate_bps: u16,               // Floor for the utilization-adjusted rate
+    pub max_rate_bps: u16,               // Ceiling, reached at full utilization
     pub min_credit_score: u16,           // 650 default
+    pub max_interest_multiple: u16,      // 20000 = interest capped at 2x principal (0 = uncapped)
+    pub circuit_version: u16,            // Accepted Noir circuit version
+    pub insurance_fee_bps: u16,          // 1000 = 10% of repaid interest to insurance
+    pub reserve_factor_bps: u16,         // 500 = 5% of repaid interest to protocol_reserves
+    pub min_reserve_bps: u16,            // Idle liquidity kept as a share of total_borrowed
+    pub tier_max_borrow_bps: [u16; 6],   // Max loan as % of collateral, indexed by CreditTier
+    pub tier_liquidation_discount_bps: [u16; 6], // Subtracted from liquidation_threshold, indexed by CreditTier
+    pub tier_collateral_discount_bps: [u16; 6],  // Subtracted from credit_collateral_ratio, indexed by CreditTier
+    pub tier_on_time_rebate_bps: [u16; 6],       // Share of interest refunded on on-time full repayment, indexed by CreditTier
+    pub interest_free_period: i64,       // Seconds after origination with no interest
+    pub max_loan_duration: i64,          // Longest allowed loan term in seconds
+    pub liquidation_cooldown: i64,       // Seconds a liquidated borrower may not borrow again (0 = off)
+    pub keeper_reward: u64,              // Paid from insurance fund per useful crank (0 = off)
+    pub dust_threshold: u64,             // Remaining debt at or below this closes the loan on repay (0 = off)
+    pub max_borrow_per_tx: u64,          // Largest single borrow, bounding one bad oracle read (0 = uncapped)
+    pub max_proof_len: u32,              // Bytes; larger proofs are rejected before parsing
+    pub max_public_inputs_len: u32,      // Bytes
     pub total_deposits: u64,
+    pub total_positions: u64,            // Sum of lender and protocol deposit units; bad debt lowers what each unit redeems for
     pub total_borrowed: u64,
     pub active_loans: u32,
+    pub rate_weighted_principal: u128,   // Sum of principal x snapshot rate over open loans
+    pub weighted_avg_rate: u16,          // Principal-weighted average rate of open loans, in bps
     pub total_interest_accrued: u64,
+    pub bad_debt: u64,
+    pub insurance_fund: u64,
+    pub protocol_reserves: u64,          // Protocol's earmarked share of interest, held in the vault
+    pub protocol_deposits: u64,          // Reserves reinvested as lendable liquidity, counted in total_deposits
+    pub borrow_index: u128,              // Cumulative borrow growth, INDEX_SCALE = 1.0
+    pub last_index_update: i64,
+    pub total_collateral_savings: u64,   // Collateral units credit-verified borrowers did not have to lock
     pub created_at: i64,
     pub updated_at: i64,
     pub is_active: bool,
     pub accepts_credit_loans: bool,
+    pub auction_liquidation: bool,       // Dutch-auction bonus instead of fixed 5%
+    pub emergency_mode: bool,            // Lenders may exit with a pro-rata share of principal
+    pub require_proof_for_regulatory: bool, // Regulatory disclosures for this pool must carry a fresh ZK proof
+    pub close_factor_bps: u16,           // Max share of debt one liquidation may repay (0 = all)
+    pub seizure_order: SeizureOrder,
+    pub min_tier_for_access: CreditTier, // Lowest tier allowed to borrow at all, standard loans included (Unknown = open)
     pub _reserved: [u8; 64],
     pub bump: u8,
 }
 
 impl LendingPool {
-    pub const LEN: usize = 8 + 32 + 8 + 32 + 32 + 2 + 2 + 2 + 2 + 2 + 8 + 8 + 4 + 8 + 8 + 8 + 1 + 1 + 64 + 1;
+    pub const INDEX_SCALE: u128 = 1_000_000_000_000_000_000;
+    pub const DEFAULT_MAX_LOAN_DURATION: i64 = 365 * 24 * 60 * 60;
+    pub const KEEPER_MIN_ACCRUAL_INTERVAL: i64 = 24 * 60 * 60;
+    pub const DEFAULT_MIN_RESERVE_BPS: u16 = 500;
+    pub const DEFAULT_RESERVE_FACTOR_BPS: u16 = 500;
+    pub const DEFAULT_MIN_OPENING_HEALTH_FACTOR: u16 = 11500;
+    pub const DEFAULT_MAX_PROOF_LEN: u32 = 1024;
+    pub const DEFAULT_MAX_PUBLIC_INPUTS_LEN: u32 = 256;
+    // Collateral ratios never go below 100%, so a 100% cap never binds until an admin lowers it
+    pub const DEFAULT_TIER_MAX_BORROW_BPS: [u16; 6] = [10000; 6];
+    pub const DEFAULT_TIER_LIQUIDATION_DISCOUNT_BPS: [u16; 6] = [0, 0, 0, 0, 250, 500];
+    pub const DEFAULT_TIER_COLLATERAL_DISCOUNT_BPS: [u16; 6] = [0, 0, 0, 0, 250, 500];
+    pub const DEFAULT_CLOSE_FACTOR_BPS: u16 = 5000;
+    pub const DEFAULT_TIER_ON_TIME_REBATE_BPS: [u16; 6] = [0, 0, 0, 500, 1000, 1500];
+    pub const INTEREST_BUFFER_PERIOD: i64 = 30 * 24 * 60 * 60;
+    pub const LEN: usize = 8 + 32 + 8 + 32 + 32 + 32 + 32 + 32 + 32 + 32 + 32 + 32 + 1 + 1 + 2 + 2 + 2 + 2 + 2 + 2 + 2 + 2 + 2 + 2 + 2 + 2 + 2 + 12 + 12 + 12 + 12 + 8 + 8 + 8 + 8 + 8 + 8 + 4 + 4 + 8 + 8 + 8 + 4 + 16 + 2 + 8 + 8 + 8 + 8 + 8 + 16 + 8 + 8 + 8 + 8 + 1 + 1 + 1 + 1 + 1 + 2 + 1 + 1 + 64 + 1;
+
+    pub fn has_admin_multisig(&self) -> bool {
+        self.admin_multisig != Pubkey::default()
+    }
+
+    /// When a multisig is attached, admin actions also need threshold approval
+    pub fn require_admin_approval(&self, multisig: Option<&Account<AdminMultisig>>, accounts: &[AccountInfo]) -> Result<()> {
+        if !self.has_admin_multisig() { return Ok(()); }
+        let multisig = multisig.ok_or(PrivateScoreError::Unauthorized)?;
+        require_keys_eq!(multisig.key(), self.admin_multisig, PrivateScoreError::Unauthorized);
+        multisig.verify_approvals(accounts)
+    }
+
+    pub fn has_tier_table(&self) -> bool {
+        self.tier_table != Pubkey::default()
+    }
+
+    /// When a tier table is attached it must be passed, so the stored tier
+    /// cannot be used in its place
+    pub fn require_tier_table(&self, table: Option<&Account<TierTable>>) -> Result<()> {
+        if !self.has_tier_table() { return Ok(()); }
+        let table = table.ok_or(PrivateScoreError::TierDoesNotQualify)?;
+        require_keys_eq!(table.key(), self.tier_table, PrivateScoreError::TierDoesNotQualify);
+        Ok(())
+    }
+
+    pub fn has_price_oracle(&self) -> bool {
+        self.price_oracle != Pubkey::default()
+    }
+
+    /// Once a price oracle is attached its feed must be passed and fresh;
+    /// unpriced pools get `None` and value collateral 1:1.
+    pub fn checked_feed<'a, 'info>(
+        &self,
+        feed: Option<&'a Account<'info, PriceFeed>>,
+        current_time: i64,
+        max_staleness: i64,
+    ) -> Result<Option<&'a Account<'info, PriceFeed>>> {
+        if !self.has_price_oracle() { return Ok(None); }
+        let feed = feed.ok_or(PrivateScoreError::InvalidOracle)?;
+        require_keys_eq!(feed.key(), self.price_oracle, PrivateScoreError::InvalidOracle);
+        require!(feed.price > 0, PrivateScoreError::InvalidOracle);
+        require!(!feed.is_stale(current_time, max_staleness), PrivateScoreError::StaleOraclePrice);
+        Ok(Some(feed))
+    }
+
+    /// Collateral value in loan units, through the checked feed
+    pub fn collateral_value(
+        &self,
+        collateral_amount: u64,
+        feed: Option<&Account<PriceFeed>>,
+        current_time: i64,
+        max_staleness: i64,
+    ) -> Result<u64> {
+        let amount = self.to_loan_units(collateral_amount);
+        match self.checked_feed(feed, current_time, max_staleness)? {
+            Some(feed) => Ok(feed.value_of(amount)),
+            None => Ok(amount),
+        }
+    }
+
+    /// Collateral units that must stay locked to back `value` loan units
+    pub fn required_collateral_for_value(
+        &self,
+        value: u64,
+        feed: Option<&Account<PriceFeed>>,
+        current_time: i64,
+        max_staleness: i64,
+    ) -> Result<u64> {
+        let feed = self.checked_feed(feed, current_time, max_staleness)?;
+        Ok(self.collateral_for_value(value, feed))
+    }
+
+    /// Token count alone can meet the ratio while the collateral is worth less;
+    /// priced pools also require its oracle value to cover `amount` at `ratio`.
+    /// The feed prices the pool's own collateral mint only.
+    pub fn require_collateral_value(
+        &self,
+        collateral_mint: &Pubkey,
+        collateral_amount: u64,
+        amount: u64,
+        ratio: u16,
+        feed: Option<&Account<PriceFeed>>,
+        current_time: i64,
+        max_staleness: i64,
+    ) -> Result<()> {
+        if !self.has_price_oracle() { return Ok(()); }
+        require_keys_eq!(*collateral_mint, self.collateral_mint, PrivateScoreError::InvalidTokenMint);
+        let value = self.collateral_value(collateral_amount, feed, current_time, max_staleness)?;
+        require!(
+            value as u128 * 10000 >= amount as u128 * ratio as u128,
+            PrivateScoreError::InsufficientCollateral
+        );
+        Ok(())
+    }
+
+    /// Collateral units worth `loan_amount`; pass the feed already checked by
+    /// `collateral_value`. Unpriced pools convert 1:1.
+    pub fn collateral_for_value(&self, loan_amount: u64, feed: Option<&Account<PriceFeed>>) -> u64 {
+        let amount = match feed {
+            Some(feed) if self.has_price_oracle() => feed.amount_for(loan_amount),
+            _ => loan_amount,
+        };
+        self.to_collateral_units(amount)
+    }
+
+    pub fn tier_has_access(&self, tier: CreditTier) -> bool {
+        tier.to_u8() >= self.min_tier_for_access.to_u8()
+    }
+
+    pub fn has_blocklist(&self) -> bool {
+        self.blocklist != Pubkey::default()
+    }
+
+    /// When a blocklist is attached it must be passed, and `address` must not be on it
+    pub fn require_not_blocked(&self, blocklist: Option<&Account<Blocklist>>, address: &Pubkey) -> Result<()> {
+        if !self.has_blocklist() { return Ok(()); }
+        let blocklist = blocklist.ok_or(PrivateScoreError::Unauthorized)?;
+        require_keys_eq!(blocklist.key(), self.blocklist, PrivateScoreError::Unauthorized);
+        require!(!blocklist.contains(address), PrivateScoreError::Unauthorized);
+        Ok(())
+    }
+
+    pub fn has_proof_replay_cache(&self) -> bool {
+        self.proof_replay_cache != Pubkey::default()
+    }
+
+    /// When a replay cache is attached it must be passed, and `proof_hash` must not be in it
+    pub fn record_proof(&self, cache: Option<&mut Account<ProofReplayCache>>, proof_hash: [u8; 32], current_time: i64) -> Result<()> {
+        if !self.has_proof_replay_cache() { return Ok(()); }
+        let cache = cache.ok_or(PrivateScoreError::ProofVerificationFailed)?;
+        require_keys_eq!(cache.key(), self.proof_replay_cache, PrivateScoreError::ProofVerificationFailed);
+        cache.record(proof_hash, current_time)
+    }
+
+    /// Add a newly opened loan, at its snapshot rate, to the pool totals
+    pub fn open_loan(&mut self, principal: u64, rate: u16) {
+        self.total_borrowed = self.total_borrowed.saturating_add(principal);
+        self.active_loans = self.active_loans.saturating_add(1);
+        self.rate_weighted_principal = self.rate_weighted_principal.saturating_add(principal as u128 * rate as u128);
+        self.update_weighted_avg_rate();
+    }
+
+    /// Remove a closed loan from the pool totals. Underflow means the loan was
+    /// already closed or the counters drifted, so it is an error, not a clamp.
+    pub fn release_loan(&mut self, principal: u64, rate: u16) -> Result<()> {
+        self.total_borrowed = self.total_borrowed.checked_sub(principal).ok_or(PrivateScoreError::InvalidAccountState)?;
+        self.active_loans = self.active_loans.checked_sub(1).ok_or(PrivateScoreError::InvalidAccountState)?;
+        self.rate_weighted_principal = self.rate_weighted_principal
+            .checked_sub(principal as u128 * rate as u128)
+            .ok_or(PrivateScoreError::InvalidAccountState)?;
+        self.update_weighted_avg_rate();
+        Ok(())
+    }
+
+    fn update_weighted_avg_rate(&mut self) {
+        self.weighted_avg_rate = if self.total_borrowed == 0 {
+            0
+        } else {
+            (self.rate_weighted_principal / self.total_borrowed as u128).min(u16::MAX as u128) as u16
+        };
+    }
+
+    /// Units credited for a deposit of `amount`, priced at the current per-unit value
+    pub fn position_for_deposit(&self, amount: u64) -> u64 {
+        if self.total_positions == 0 || self.total_deposits == 0 { return amount; }
+        (amount as u128 * self.total_positions as u128 / self.total_deposits as u128) as u64
+    }
+
+    /// Tokens `position` units redeem for; every unit bears bad debt in the same proportion
+    pub fn redeem_value(&self, position: u64) -> u64 {
+        if self.total_positions == 0 { return 0; }
+        (position as u128 * self.total_deposits as u128 / self.total_positions as u128) as u64
+    }
+
+    pub fn record_deposit(&mut self, amount: u64, position: u64) {
+        self.total_deposits = self.total_deposits.saturating_add(amount);
+        self.total_positions = self.total_positions.saturating_add(position);
+    }
+
+    pub fn record_redemption(&mut self, position: u64, value: u64) {
+        self.total_deposits = self.total_deposits.saturating_sub(value);
+        self.total_positions = self.total_positions.saturating_sub(position);
+    }
 
     pub fn available_liquidity(&self) -> u64 {
         self.total_deposits.saturating_sub(self.total_borrowed)
     }
 
+    /// Liquidity lenders may withdraw while keeping the reserve buffer idle
+    pub fn withdrawable_liquidity(&self) -> u64 {
+        let reserve = (self.total_borrowed as u128 * self.min_reserve_bps as u128 / 10000) as u64;
+        self.available_liquidity().saturating_sub(reserve)
+    }
+
     pub fn utilization_rate(&self) -> u16 {
         if self.total_deposits == 0 { return 0; }
         ((self.total_borrowed as u128 * 10000) / self.total_deposits as u128) as u16
     }
 
+    /// Borrow rate rises linearly from `interest_rate` to `max_rate_bps` with utilization
+    pub fn borrow_rate(&self) -> u16 {
+        let slope = self.max_rate_bps.saturating_sub(self.interest_rate) as u32;
+        let rate = self.interest_rate as u32 + slope * self.utilization_rate().min(10000) as u32 / 10000;
+        self.clamp_rate(rate.min(u16::MAX as u32) as u16)
+    }
+
+    /// Lender APY: the borrow rate earned on the utilized share, less the
+    /// insurance fee and reserve factor withheld from repaid interest
+    pub fn supply_rate(&self) -> u16 {
+        let utilization = self.utilization_rate().min(10000) as u64;
+        let lender_share = 10000u64
+            .saturating_sub(self.insurance_fee_bps as u64)
+            .saturating_sub(self.reserve_factor_bps as u64);
+        (self.borrow_rate() as u64 * utilization / 10000 * lender_share / 10000) as u16
+    }
+
+    /// 0% pools: the rate can never leave zero, so the index never grows
+    pub fn is_zero_rate(&self) -> bool {
+        self.max_rate_bps == 0
+    }
+
+    pub fn clamp_rate(&self, rate: u16) -> u16 {
+        rate.clamp(self.min_rate_bps, self.max_rate_bps.max(self.min_rate_bps))
+    }
+
+    /// Grow the borrow index at the current rate. Call before anything that changes
+    /// total_borrowed or total_deposits, so each interval is charged at the rate in force.
+    pub fn accrue_borrow_index(&mut self, current_time: i64) -> u128 {
+        if self.borrow_index == 0 {
+            self.borrow_index = Self::INDEX_SCALE;
+        }
+        let elapsed = current_time.saturating_sub(self.last_index_update);
+        if elapsed > 0 && self.last_index_update > 0 && !self.is_zero_rate() {
+            let seconds_per_year: u128 = 365 * 24 * 60 * 60;
+            let growth = self.borrow_index * self.borrow_rate() as u128 * elapsed as u128 / (seconds_per_year * 10000);
+            self.borrow_index = self.borrow_index.saturating_add(growth);
+        }
+        self.last_index_update = self.last_index_update.max(current_time);
+        self.borrow_index
+    }
+
+    /// Record an uncovered liquidation; lost principal reduces lenders' claimable deposits
+    pub fn record_bad_debt(&mut self, shortfall: u64, principal_loss: u64) {
+        self.bad_debt = self.bad_debt.saturating_add(shortfall);
+        self.total_deposits = self.total_deposits.saturating_sub(principal_loss);
+    }
+
+    /// Cover a shortfall from the insurance fund first; the remainder becomes bad debt
+    pub fn absorb_shortfall(&mut self, shortfall: u64, principal_loss: u64) -> u64 {
+        let covered = shortfall.min(self.insurance_fund);
+        self.insurance_fund -= covered;
+        let uncovered = shortfall - covered;
+        if uncovered > 0 {
+            self.record_bad_debt(uncovered, principal_loss.saturating_sub(covered));
+        }
+        covered
+    }
+
+    /// Slice of repaid interest set aside for the insurance fund
+    pub fn collect_insurance_fee(&mut self, interest: u64) -> u64 {
+        let fee = (interest as u128 * self.insurance_fee_bps as u128 / 10000) as u64;
+        self.insurance_fund = self.insurance_fund.saturating_add(fee);
+        fee
+    }
+
+    /// Slice of repaid interest set aside as protocol reserves
+    pub fn collect_protocol_reserve(&mut self, interest: u64) -> u64 {
+        let share = (interest as u128 * self.reserve_factor_bps as u128 / 10000) as u64;
+        self.protocol_reserves = self.protocol_reserves.saturating_add(share);
+        share
+    }
+
+    /// Split repaid interest: the insurance and reserve slices are earmarked and
+    /// the rest is credited to lenders through `total_deposits`, which is what
+    /// `supply_rate` quotes and `redeem_value` pays out
+    pub fn settle_interest(&mut self, interest: u64) -> u64 {
+        self.total_interest_accrued = self.total_interest_accrued.saturating_add(interest);
+        let fee = self.collect_insurance_fee(interest);
+        let reserve = self.collect_protocol_reserve(interest);
+        let lender_share = interest.saturating_sub(fee).saturating_sub(reserve);
+        self.total_deposits = self.total_deposits.saturating_add(lender_share);
+        lender_share
+    }
+
+    /// Refund owed on a loan's interest when it is fully repaid on time
+    pub fn on_time_rebate(&self, interest: u64, tier: CreditTier) -> u64 {
+        let rebate_bps = self.tier_on_time_rebate_bps[tier.to_u8() as usize].min(10000);
+        (interest as u128 * rebate_bps as u128 / 10000) as u64
+    }
+
+    /// Keeper rewards come out of the insurance fund, never lender deposits
+    pub fn take_keeper_reward(&mut self) -> u64 {
+        self.take_keeper_reward_up_to(u64::MAX)
+    }
+
+    /// Same, but never more than `cap`, e.g. the interest a crank actually accrued
+    pub fn take_keeper_reward_up_to(&mut self, cap: u64) -> u64 {
+        let reward = self.keeper_reward.min(cap).min(self.insurance_fund);
+        self.insurance_fund -= reward;
+        reward
+    }
+
     pub fn get_collateral_ratio(&self, is_credit_verified: bool) -> u16 {
         if is_credit_verified && self.accepts_credit_loans {
             self.credit_collateral_ratio
@@ -48,8 +427,118 @@ impl LendingPool {
         }
     }
 
-    pub fn has_liquidity(&self, amount: u64) -> bool {
-        self.available_liquidity() >= amount
+    /// Credit ratio for a verified tier; never below the opening health buffer
+    pub fn collateral_ratio_for_tier(&self, tier: CreditTier) -> u16 {
+        let discount = self.tier_collateral_discount_bps[tier.to_u8() as usize];
+        self.get_collateral_ratio(true)
+            .saturating_sub(discount)
+            .max(self.min_opening_health_factor)
+    }
+
+    /// Collateral (in collateral-mint units) needed to borrow `amount` loan-mint units
+    pub fn get_required_collateral(&self, amount: u64, is_credit_verified: bool) -> u64 {
+        self.required_collateral_at(amount, self.get_collateral_ratio(is_credit_verified))
+    }
+
+    pub fn required_collateral_at(&self, amount: u64, ratio: u16) -> u64 {
+        self.to_collateral_units((amount as u128 * ratio as u128 / 10000) as u64)
+    }
+
+    /// Rescale loan-mint units to collateral-mint units (1:1 per whole token)
+    pub fn to_collateral_units(&self, loan_amount: u64) -> u64 {
+        convert_decimals(loan_amount, self.loan_decimals, self.collateral_decimals)
+    }
+
+    /// Rescale collateral-mint units to loan-mint units (1:1 per whole token)
+    pub fn to_loan_units(&self, collateral_amount: u64) -> u64 {
+        convert_decimals(collateral_amount, self.collateral_decimals, self.loan_decimals)
+    }
+
+    pub fn max_borrow_for_tier(&self, tier: CreditTier, collateral_amount: u64) -> u64 {
+        let limit_bps = self.tier_max_borrow_bps[tier.to_u8() as usize];
+        (self.to_loan_units(collateral_amount) as u128 * limit_bps as u128 / 10000) as u64
+    }
+
+    /// Higher tiers get more runway before liquidation, never below 100%
+    pub fn liquidation_threshold_for_tier(&self, tier: CreditTier) -> u16 {
+        let discount = self.tier_liquidation_discount_bps[tier.to_u8() as usize];
+        self.liquidation_threshold.saturating_sub(discount).max(10000)
+    }
+
+    /// A zero duration means "the pool maximum"; longer terms are rejected
+    pub fn effective_loan_duration(&self, duration: i64) -> Result<i64> {
+        let effective = if duration == 0 { self.max_loan_duration } else { duration };
+        require!(effective > 0 && effective <= self.max_loan_duration, PrivateScoreError::InvalidLoanDuration);
+        Ok(effective)
+    }
+
+    /// Collateral value over debt in bps for a position about to be opened
+    pub fn opening_health_factor(&self, collateral_amount: u64, amount: u64) -> u64 {
+        if amount == 0 { return u64::MAX; }
+        (self.to_loan_units(collateral_amount) as u128 * 10000 / amount as u128) as u64
+    }
+
+    /// Reject oversized proof data before spending compute on it
+    pub fn check_proof_size(&self, proof: &[u8], public_inputs: &[u8]) -> Result<()> {
+        require!(proof.len() <= self.max_proof_len as usize, PrivateScoreError::InvalidProof);
+        require!(public_inputs.len() <= self.max_public_inputs_len as usize, PrivateScoreError::InvalidPublicInputs);
+        Ok(())
+    }
+
+    /// The pool's own collateral mint, or one added to its whitelist
+    pub fn accepts_collateral(&self, mint: &Pubkey, whitelist: Option<&CollateralWhitelist>) -> bool {
+        *mint == self.collateral_mint || whitelist.map_or(false, |w| w.contains(mint))
+    }
+
+    /// Most of `total_debt` a single liquidation may repay
+    pub fn max_liquidation_repay(&self, total_debt: u64) -> u64 {
+        if self.close_factor_bps == 0 || self.close_factor_bps >= 10000 { return total_debt; }
+        (total_debt as u128 * self.close_factor_bps as u128 / 10000) as u64
+    }
+
+    /// Emergency payout for `position`: its pro-rata share of the lendable vault
+    /// balance, never more than the position's redeem value. Interest is ignored.
+    pub fn emergency_payout(&self, position: u64, vault_balance: u64) -> u64 {
+        if self.total_positions == 0 { return 0; }
+        let earmarked = self.insurance_fund.saturating_add(self.protocol_reserves);
+        let liquidity = vault_balance.saturating_sub(earmarked).min(self.total_deposits);
+        (position as u128 * liquidity as u128 / self.total_positions as u128) as u64
+    }
+
+    /// Move up to `amount` of reserves into lendable liquidity as a protocol-owned deposit
+    pub fn reinvest_reserves(&mut self, amount: u64) -> u64 {
+        let reinvested = amount.min(self.protocol_reserves);
+        self.protocol_reserves -= reinvested;
+        self.protocol_deposits = self.protocol_deposits.saturating_add(reinvested);
+        let position = self.position_for_deposit(reinvested);
+        self.record_deposit(reinvested, position);
+        reinvested
+    }
+
+    pub fn within_borrow_cap(&self, amount: u64) -> bool {
+        self.max_borrow_per_tx == 0 || amount <= self.max_borrow_per_tx
+    }
+
+    pub fn is_dust(&self, debt: u64) -> bool {
+        debt > 0 && debt <= self.dust_threshold
+    }
+
+    /// Interest the book would accrue over INTEREST_BUFFER_PERIOD once `amount`
+    /// more is lent, at the higher of the current and the average open-loan rate
+    pub fn interest_buffer(&self, amount: u64) -> u64 {
+        let seconds_per_year: u128 = 365 * 24 * 60 * 60;
+        let debt = self.total_borrowed.saturating_add(amount) as u128;
+        let rate = self.borrow_rate().max(self.weighted_avg_rate) as u128;
+        (debt * rate * Self::INTEREST_BUFFER_PERIOD as u128 / (10000 * seconds_per_year)).min(u64::MAX as u128) as u64
+    }
+
+    /// Insurance and protocol reserves sit in the same vault but are never lendable.
+    /// Lendable liquidity must also leave the interest buffer idle, so lenders
+    /// keep withdrawal headroom as interest on existing loans builds up
+    pub fn has_liquidity(&self, amount: u64, vault_balance: u64) -> bool {
+        let earmarked = self.insurance_fund.saturating_add(self.protocol_reserves);
+        let lendable = self.available_liquidity().min(vault_balance.saturating_sub(earmarked));
+        lendable >= amount.saturating_add(self.interest_buffer(amount))
     }
 
     pub fn collateral_savings_bps(&self) -> u16 {
@@ -57,6 +546,16 @@ impl LendingPool {
     }
 }
 
+fn convert_decimals(amount: u64, from_decimals: u8, to_decimals: u8) -> u64 {
+    if to_decimals >= from_decimals {
+        let scale = 10u128.pow((to_decimals - from_decimals) as u32);
+        (amount as u128 * scale).min(u64::MAX as u128) as u64
+    } else {
+        let scale = 10u128.pow((from_decimals - to_decimals) as u32);
+        (amount as u128 / scale) as u64
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct PoolStats {
     pub total_volume: u64,
@@ -64,4 +563,157 @@ pub struct PoolStats {
     pub total_credit_loans: u64,
     pub average_loan_size: u64,
     pub total_liquidations: u32,
-}
\ No newline at end of file
+    pub total_collateral_savings: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_deposit_is_priced_one_to_one() {
+        let pool = LendingPool::default();
+        assert_eq!(pool.position_for_deposit(100), 100);
+        assert_eq!(pool.redeem_value(100), 0);
+    }
+
+    #[test]
+    fn positions_share_bad_debt_pro_rata() {
+        let mut pool = LendingPool::default();
+        pool.record_deposit(1_000, 1_000);
+        pool.record_bad_debt(200, 200);
+        assert_eq!(pool.redeem_value(500), 400);
+        assert_eq!(pool.position_for_deposit(400), 500);
+    }
+
+    #[test]
+    fn insurance_fund_absorbs_shortfall_before_lenders() {
+        let mut pool = LendingPool { insurance_fund: 500, total_deposits: 1_000, ..Default::default() };
+        assert_eq!(pool.absorb_shortfall(300, 300), 300);
+        assert_eq!(pool.insurance_fund, 200);
+        assert_eq!(pool.bad_debt, 0);
+        assert_eq!(pool.total_deposits, 1_000);
+    }
+
+    #[test]
+    fn uncovered_shortfall_becomes_bad_debt() {
+        let mut pool = LendingPool { insurance_fund: 100, total_deposits: 1_000, ..Default::default() };
+        assert_eq!(pool.absorb_shortfall(300, 300), 100);
+        assert_eq!(pool.insurance_fund, 0);
+        assert_eq!(pool.bad_debt, 200);
+        assert_eq!(pool.total_deposits, 800);
+    }
+
+    #[test]
+    fn default_tier_borrow_caps_do_not_bind() {
+        let mut pool = LendingPool {
+            loan_decimals: 6,
+            collateral_decimals: 9,
+            tier_max_borrow_bps: LendingPool::DEFAULT_TIER_MAX_BORROW_BPS,
+            ..Default::default()
+        };
+        assert_eq!(pool.max_borrow_for_tier(CreditTier::Unknown, 2_000_000_000), 2_000_000);
+        pool.tier_max_borrow_bps[CreditTier::Poor.to_u8() as usize] = 5000;
+        assert_eq!(pool.max_borrow_for_tier(CreditTier::Poor, 2_000_000_000), 1_000_000);
+    }
+
+    #[test]
+    fn keeper_reward_is_limited_by_the_insurance_fund_and_cap() {
+        let mut pool = LendingPool { keeper_reward: 100, insurance_fund: 60, ..Default::default() };
+        assert_eq!(pool.take_keeper_reward(), 60);
+        assert_eq!(pool.insurance_fund, 0);
+
+        pool.insurance_fund = 1_000;
+        assert_eq!(pool.take_keeper_reward_up_to(30), 30);
+        assert_eq!(pool.insurance_fund, 970);
+    }
+
+    #[test]
+    fn borrow_index_grows_at_the_borrow_rate() {
+        const YEAR: i64 = 365 * 24 * 60 * 60;
+        let mut pool = LendingPool { interest_rate: 1000, max_rate_bps: 1000, ..Default::default() };
+        assert_eq!(pool.accrue_borrow_index(100), LendingPool::INDEX_SCALE);
+        assert_eq!(pool.accrue_borrow_index(100 + YEAR), LendingPool::INDEX_SCALE + LendingPool::INDEX_SCALE / 10);
+        assert_eq!(pool.last_index_update, 100 + YEAR);
+    }
+
+    #[test]
+    fn zero_rate_pool_index_never_moves() {
+        let mut pool = LendingPool::default();
+        pool.accrue_borrow_index(100);
+        assert_eq!(pool.accrue_borrow_index(100_000_000), LendingPool::INDEX_SCALE);
+    }
+
+    #[test]
+    fn close_factor_caps_a_single_liquidation() {
+        let mut pool = LendingPool { close_factor_bps: 5000, ..Default::default() };
+        assert_eq!(pool.max_liquidation_repay(1_000), 500);
+        pool.close_factor_bps = 0;
+        assert_eq!(pool.max_liquidation_repay(1_000), 1_000);
+        pool.close_factor_bps = 10000;
+        assert_eq!(pool.max_liquidation_repay(1_000), 1_000);
+    }
+
+    #[test]
+    fn seizure_order_picks_which_collateral_goes_first() {
+        assert_eq!(SeizureOrder::BorrowerFirst.split(150, 100, 100), (100, 50));
+        assert_eq!(SeizureOrder::CosignerFirst.split(150, 100, 100), (50, 100));
+        assert_eq!(SeizureOrder::BorrowerFirst.split(300, 100, 100), (100, 100));
+    }
+
+    #[test]
+    fn settle_interest_credits_the_lender_share_to_deposits() {
+        let mut pool = LendingPool {
+            insurance_fee_bps: 1000,
+            reserve_factor_bps: 500,
+            total_deposits: 10_000,
+            ..Default::default()
+        };
+        assert_eq!(pool.settle_interest(1_000), 850);
+        assert_eq!(pool.insurance_fund, 100);
+        assert_eq!(pool.protocol_reserves, 50);
+        assert_eq!(pool.total_deposits, 10_850);
+        assert_eq!(pool.total_interest_accrued, 1_000);
+    }
+
+    #[test]
+    fn borrow_rate_follows_utilization_within_the_band() {
+        let mut pool = LendingPool {
+            interest_rate: 500,
+            max_rate_bps: 2500,
+            total_deposits: 1_000,
+            total_borrowed: 500,
+            ..Default::default()
+        };
+        assert_eq!(pool.borrow_rate(), 1500);
+        pool.min_rate_bps = 2000;
+        assert_eq!(pool.borrow_rate(), 2000);
+    }
+
+    #[test]
+    fn withdrawals_keep_the_reserve_buffer() {
+        let pool = LendingPool { total_deposits: 1_000, total_borrowed: 600, min_reserve_bps: 1000, ..Default::default() };
+        assert_eq!(pool.available_liquidity(), 400);
+        assert_eq!(pool.withdrawable_liquidity(), 340);
+    }
+
+    #[test]
+    fn on_time_rebate_scales_with_tier() {
+        let pool = LendingPool { tier_on_time_rebate_bps: LendingPool::DEFAULT_TIER_ON_TIME_REBATE_BPS, ..Default::default() };
+        assert_eq!(pool.on_time_rebate(1_000, CreditTier::Excellent), 150);
+        assert_eq!(pool.on_time_rebate(1_000, CreditTier::Poor), 0);
+    }
+
+    #[test]
+    fn emergency_payout_excludes_earmarked_funds() {
+        let pool = LendingPool {
+            total_deposits: 1_000,
+            total_positions: 1_000,
+            insurance_fund: 100,
+            protocol_reserves: 50,
+            ..Default::default()
+        };
+        assert_eq!(pool.emergency_payout(500, 500), 175);
+        assert_eq!(pool.emergency_payout(500, 5_000), 500);
+    }
+}
@@ -3,6 +3,8 @@
 //! ═══════════════════════════════════════════════════════════════════════════
 
 use anchor_lang::prelude::*;
+use crate::errors::PrivateScoreError;
+use crate::state::LendingPool;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
 pub enum LoanStatus {
@@ -28,34 +30,75 @@ pub struct Loan {
     pub principal: u64,
     pub interest_accrued: u64,
     pub amount_repaid: u64,
+    pub interest_repaid: u64,            // Part of amount_repaid applied to interest; repayments clear interest first
     pub collateral_locked: u64,
     pub collateral_mint: Pubkey,
     pub collateral_ratio: u16,
-    pub interest_rate: u16,
+    pub interest_rate: u16,              // Pool rate at origination; accrual follows the pool's borrow index
+    pub max_interest_multiple: u16,
     pub loan_type: LoanType,
     pub status: LoanStatus,
     pub proof_hash: [u8; 32],
     pub credit_commitment: [u8; 32],
     pub created_at: i64,
     pub last_accrual_at: i64,
+    pub interest_free_until: i64,
     pub closed_at: i64,
     pub repayment_count: u16,
     pub repaid_on_time: bool,
     pub duration: i64,
     pub due_date: i64,
+    pub installment_amount: u64,
+    pub installment_interval: i64,
+    pub num_installments: u16,
+    pub next_installment_due: i64,
+    pub is_delinquent: bool,
+    pub ever_delinquent: bool,           // Set on the first missed installment; keepers are paid for it once
+    pub auction_started_at: i64,
+    pub auction_start_bonus_bps: u16,
+    pub auction_max_bonus_bps: u16,
+    pub auction_duration: i64,
+    pub auction_cancel_rewarded: bool,   // A keeper was already paid for cancelling an auction on this loan
+    pub cosigner: Pubkey,                // Default = no guarantor
+    pub cosigner_collateral: u64,        // Pledged by the cosigner, seized after the borrower's
+    pub liquidation_threshold: u16,      // Tier-adjusted at origination (0 = use the pool's)
+    pub interest_remainder: u64,         // Sub-unit interest carried between accruals, scaled by INDEX_SCALE
+    pub entry_index: u128,               // Pool borrow index at the last accrual
     pub _reserved: [u8; 32],
     pub bump: u8,
 }
 
 impl Loan {
-    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 32 + 2 + 2 + 1 + 1 + 32 + 32 + 8 + 8 + 8 + 2 + 1 + 8 + 8 + 32 + 1;
+    pub const AUCTION_START_BONUS_BPS: u16 = 100;   // 1%
+    pub const AUCTION_MAX_BONUS_BPS: u16 = 1000;    // 10%
+    pub const AUCTION_DURATION: i64 = 30 * 60;
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 32 + 2 + 2 + 2 + 1 + 1 + 32 + 32 + 8 + 8 + 8 + 8 + 2 + 1 + 8 + 8 + 8 + 8 + 2 + 8 + 1 + 1 + 8 + 2 + 2 + 8 + 1 + 32 + 8 + 2 + 8 + 16 + 32 + 1;
 
     pub fn total_debt(&self) -> u64 {
         self.principal.saturating_add(self.interest_accrued).saturating_sub(self.amount_repaid)
     }
 
     pub fn outstanding_principal(&self) -> u64 {
-        self.principal.saturating_sub(self.amount_repaid.min(self.principal))
+        let principal_repaid = self.amount_repaid.saturating_sub(self.interest_repaid);
+        self.principal.saturating_sub(principal_repaid.min(self.principal))
+    }
+
+    pub fn outstanding_interest(&self) -> u64 {
+        self.interest_accrued.saturating_sub(self.interest_repaid)
+    }
+
+    /// Apply a repayment to accrued interest first, then principal, so accrual
+    /// keeps running on the principal actually still owed. Returns the principal part.
+    pub fn apply_repayment(&mut self, amount: u64) -> u64 {
+        let to_interest = amount.min(self.outstanding_interest());
+        self.interest_repaid = self.interest_repaid.saturating_add(to_interest);
+        self.amount_repaid = self.amount_repaid.saturating_add(amount);
+        amount - to_interest
+    }
+
+    /// Collateral the remaining debt needs at the loan's ratio, in loan-mint units
+    pub fn required_collateral_value(&self) -> u64 {
+        (self.total_debt() as u128 * self.collateral_ratio as u128 / 10000) as u64
     }
 
     pub fn health_factor(&self, collateral_value_usd: u64, debt_value_usd: u64) -> u64 {
@@ -70,39 +113,267 @@ impl Loan {
         collateral_value < required
     }
 
-    pub fn accrue_interest(&mut self, current_time: i64) -> u64 {
-        let elapsed = current_time.saturating_sub(self.last_accrual_at);
-        if elapsed <= 0 || self.status != LoanStatus::Active { return 0; }
+    /// Charge interest for the pool index growth since the last accrual, i.e.
+    /// debt grows by `principal * current_index / entry_index`. Pass the index
+    /// from `LendingPool::accrue_borrow_index`.
+    /// Closed loans must never accrue; callers get LoanNotActive instead of a silent no-op
+    pub fn accrue_interest(&mut self, current_index: u128, current_time: i64) -> Result<u64> {
+        require!(self.status == LoanStatus::Active, PrivateScoreError::LoanNotActive);
+
+        let entry_index = self.entry_index;
+        let period = current_time.saturating_sub(self.last_accrual_at);
+        // Only the part of the interval after the interest-free window accrues
+        let accrual_start = self.last_accrual_at.max(self.interest_free_until);
+        let elapsed = current_time.saturating_sub(accrual_start);
+        self.entry_index = current_index;
+        // Also the zero-rate fast path: a 0% pool's index never moves, so debt stays at principal
+        if elapsed <= 0 || entry_index == 0 || current_index <= entry_index {
+            self.last_accrual_at = self.last_accrual_at.max(current_time);
+            return Ok(0);
+        }
+
+        // Index growth over the period, prorated when it straddles the interest-free window
+        let mut growth = (current_index - entry_index) * LendingPool::INDEX_SCALE / entry_index;
+        if elapsed < period {
+            growth = growth * elapsed as u128 / period as u128;
+        }
+
+        // Carry the truncated remainder so frequent accruals lose nothing to rounding
+        let scaled = self.outstanding_principal() as u128 * growth + self.interest_remainder as u128;
+        let interest = (scaled / LendingPool::INDEX_SCALE).min(u64::MAX as u128) as u64;
+        self.interest_remainder = (scaled % LendingPool::INDEX_SCALE) as u64;
 
-        let seconds_per_year: i64 = 365 * 24 * 60 * 60;
-        let interest = (self.outstanding_principal() as u128
-            * self.interest_rate as u128
-            * elapsed as u128
-            / (seconds_per_year as u128 * 10000)) as u64;
+        let headroom = self.interest_headroom();
+        if interest >= headroom {
+            self.interest_remainder = 0;
+        }
+        let interest = interest.min(headroom);
 
         self.interest_accrued = self.interest_accrued.saturating_add(interest);
         self.last_accrual_at = current_time;
-        interest
+        Ok(interest)
+    }
+
+    /// Interest that can still accrue before hitting the pool's cap
+    pub fn interest_headroom(&self) -> u64 {
+        if self.max_interest_multiple == 0 { return u64::MAX; }
+        let cap = (self.principal as u128 * self.max_interest_multiple as u128 / 10000) as u64;
+        cap.saturating_sub(self.interest_accrued)
+    }
+
+    pub fn effective_liquidation_threshold(&self, pool_threshold: u16) -> u16 {
+        if self.liquidation_threshold == 0 { pool_threshold } else { self.liquidation_threshold }
+    }
+
+    /// Matured loans can be liquidated regardless of collateral health
+    pub fn is_liquidatable(&self, collateral_value: u64, liquidation_threshold: u16, current_time: i64) -> bool {
+        self.is_overdue(current_time) || self.is_undercollateralized(collateral_value, liquidation_threshold)
     }
 
     pub fn is_overdue(&self, current_time: i64) -> bool {
         self.due_date > 0 && current_time > self.due_date && self.status == LoanStatus::Active
     }
 
+    /// Optional installment plan; lump-sum repayment still works on top of it
+    pub fn set_installment_schedule(&mut self, num_installments: u16, installment_interval: i64) {
+        if num_installments == 0 || installment_interval <= 0 { return; }
+        self.num_installments = num_installments;
+        self.installment_interval = installment_interval;
+        self.installment_amount = self.principal.div_ceil(num_installments as u64);
+        self.next_installment_due = self.created_at.saturating_add(installment_interval);
+    }
+
+    pub fn has_installments(&self) -> bool {
+        self.num_installments > 0 && self.installment_amount > 0
+    }
+
+    pub fn installments_paid(&self) -> u16 {
+        if !self.has_installments() { return 0; }
+        (self.amount_repaid / self.installment_amount).min(self.num_installments as u64) as u16
+    }
+
+    /// Recompute the next due date from the amount repaid and flag missed installments
+    pub fn refresh_installments(&mut self, current_time: i64) {
+        if !self.has_installments() { return; }
+        let paid = self.installments_paid();
+        self.next_installment_due = if paid >= self.num_installments {
+            0
+        } else {
+            self.created_at.saturating_add(self.installment_interval.saturating_mul(paid as i64 + 1))
+        };
+        self.is_delinquent = self.next_installment_due > 0 && current_time > self.next_installment_due;
+    }
+
+    pub fn is_in_auction(&self) -> bool {
+        self.auction_started_at > 0
+    }
+
+    pub fn start_auction(&mut self, current_time: i64) {
+        self.auction_started_at = current_time;
+        self.auction_start_bonus_bps = Self::AUCTION_START_BONUS_BPS;
+        self.auction_max_bonus_bps = Self::AUCTION_MAX_BONUS_BPS;
+        self.auction_duration = Self::AUCTION_DURATION;
+    }
+
+    /// Drop the auction flag once the position has recovered
+    pub fn clear_auction(&mut self) {
+        self.auction_started_at = 0;
+        self.auction_start_bonus_bps = 0;
+        self.auction_max_bonus_bps = 0;
+        self.auction_duration = 0;
+    }
+
+    /// Liquidation bonus rises linearly from start to max over the auction window
+    pub fn auction_bonus_bps(&self, current_time: i64) -> u16 {
+        if !self.is_in_auction() { return 0; }
+        let elapsed = current_time.saturating_sub(self.auction_started_at).max(0);
+        if self.auction_duration <= 0 || elapsed >= self.auction_duration {
+            return self.auction_max_bonus_bps;
+        }
+        let range = self.auction_max_bonus_bps.saturating_sub(self.auction_start_bonus_bps) as i64;
+        self.auction_start_bonus_bps + (range * elapsed / self.auction_duration) as u16
+    }
+
+    pub fn has_cosigner(&self) -> bool {
+        self.cosigner != Pubkey::default()
+    }
+
+    /// Borrower and cosigner collateral backing the loan
+    pub fn total_collateral(&self) -> u64 {
+        self.collateral_locked.saturating_add(self.cosigner_collateral)
+    }
+
     pub fn is_credit_verified(&self) -> bool {
         self.loan_type == LoanType::CreditVerified
     }
 
-    pub fn collateral_savings(&self, standard_ratio: u16) -> u64 {
+    /// `standard_collateral` is what a standard loan of the same principal would lock,
+    /// in collateral-mint units (see `LendingPool::get_required_collateral`)
+    pub fn collateral_savings(&self, standard_collateral: u64) -> u64 {
         if self.loan_type != LoanType::CreditVerified { return 0; }
-        let standard = (self.principal as u128 * standard_ratio as u128 / 10000) as u64;
-        standard.saturating_sub(self.collateral_locked)
+        standard_collateral.saturating_sub(self.total_collateral())
     }
 }
 
+#[event]
+pub struct RepaymentMade {
+    pub loan: Pubkey,
+    pub amount: u64,
+    pub remaining_debt: u64,
+    pub health_factor: u64,
+    pub timestamp: i64,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct LoanParams {
     pub amount: u64,
     pub duration: i64,
     pub collateral_amount: u64,
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCALE: u128 = LendingPool::INDEX_SCALE;
+
+    fn loan(principal: u64) -> Loan {
+        Loan {
+            principal,
+            entry_index: SCALE,
+            last_accrual_at: 1_000,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn accrue_interest_stops_at_the_interest_cap() {
+        let mut loan = loan(1_000);
+        loan.max_interest_multiple = 500;
+        assert_eq!(loan.accrue_interest(SCALE + SCALE / 10, 2_000).unwrap(), 50);
+        assert_eq!(loan.interest_accrued, 50);
+        assert_eq!(loan.interest_remainder, 0);
+        assert_eq!(loan.accrue_interest(SCALE + SCALE / 5, 3_000).unwrap(), 0);
+        assert_eq!(loan.interest_accrued, 50);
+    }
+
+    #[test]
+    fn accrue_interest_is_uncapped_without_a_multiple() {
+        let mut loan = loan(1_000);
+        assert_eq!(loan.accrue_interest(SCALE + SCALE / 10, 2_000).unwrap(), 100);
+    }
+
+    #[test]
+    fn accrue_interest_rejects_closed_loans() {
+        let mut loan = loan(1_000);
+        loan.status = LoanStatus::Repaid;
+        assert!(loan.accrue_interest(SCALE + SCALE / 10, 2_000).is_err());
+    }
+
+    #[test]
+    fn accrue_interest_skips_the_interest_free_window() {
+        let mut loan = loan(1_000);
+        loan.interest_free_until = 1_500;
+        assert_eq!(loan.accrue_interest(SCALE + SCALE / 20, 1_400).unwrap(), 0);
+        assert_eq!(loan.entry_index, SCALE + SCALE / 20);
+        assert_eq!(loan.last_accrual_at, 1_400);
+    }
+
+    #[test]
+    fn accrue_interest_prorates_across_the_interest_free_window() {
+        let mut loan = loan(1_000);
+        loan.interest_free_until = 1_500;
+        assert_eq!(loan.accrue_interest(SCALE + SCALE / 10, 2_000).unwrap(), 50);
+    }
+
+    #[test]
+    fn installments_track_payments_and_delinquency() {
+        let mut loan = loan(1_001);
+        loan.created_at = 0;
+        loan.set_installment_schedule(4, 100);
+        assert_eq!(loan.installment_amount, 251);
+        assert_eq!(loan.next_installment_due, 100);
+
+        loan.refresh_installments(150);
+        assert!(loan.is_delinquent);
+
+        loan.amount_repaid = 251;
+        loan.refresh_installments(150);
+        assert_eq!(loan.next_installment_due, 200);
+        assert!(!loan.is_delinquent);
+
+        loan.amount_repaid = 4 * 251;
+        loan.refresh_installments(1_000);
+        assert_eq!(loan.next_installment_due, 0);
+        assert!(!loan.is_delinquent);
+    }
+
+    #[test]
+    fn accrue_interest_carries_the_remainder() {
+        let mut loan = loan(1);
+        assert_eq!(loan.accrue_interest(SCALE * 3 / 2, 2_000).unwrap(), 0);
+        assert_eq!(loan.interest_remainder as u128, SCALE / 2);
+        assert_eq!(loan.accrue_interest(SCALE * 9 / 4, 3_000).unwrap(), 1);
+        assert_eq!(loan.interest_remainder, 0);
+    }
+
+    #[test]
+    fn auction_bonus_rises_linearly_to_the_max() {
+        let mut loan = loan(1_000);
+        assert_eq!(loan.auction_bonus_bps(1_000), 0);
+        loan.start_auction(1_000);
+        assert_eq!(loan.auction_bonus_bps(1_000), Loan::AUCTION_START_BONUS_BPS);
+        assert_eq!(loan.auction_bonus_bps(1_000 + Loan::AUCTION_DURATION / 2), 550);
+        assert_eq!(loan.auction_bonus_bps(1_000 + Loan::AUCTION_DURATION * 2), Loan::AUCTION_MAX_BONUS_BPS);
+    }
+
+    #[test]
+    fn repayments_clear_interest_before_principal() {
+        let mut loan = loan(1_000);
+        loan.interest_accrued = 100;
+        assert_eq!(loan.apply_repayment(150), 50);
+        assert_eq!(loan.outstanding_interest(), 0);
+        assert_eq!(loan.outstanding_principal(), 950);
+        assert_eq!(loan.total_debt(), 950);
+    }
+}
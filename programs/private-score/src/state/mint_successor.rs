@@ -0,0 +1,23 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! MINT SUCCESSOR - Registered 1:1 replacement for a retired collateral mint
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! One per (pool, old mint). `migrate_collateral_mint` only moves loans to the
+//! mint registered here, never to an arbitrary accepted mint.
+
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct MintSuccessor {
+    pub pool: Pubkey,
+    pub old_mint: Pubkey,
+    pub new_mint: Pubkey,
+    pub registered_at: i64,
+    pub bump: u8,
+}
+
+impl MintSuccessor {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 1;
+    pub const SEED_PREFIX: &'static [u8] = b"mint_successor";
+}
@@ -1,11 +1,35 @@
 //! State module - Account structures for PrivateScore
 
+pub mod admin_multisig;
+pub mod alt_repay_token;
+pub mod blocklist;
+pub mod collateral_whitelist;
 pub mod credit_record;
+pub mod global_config;
+pub mod lender_position;
 pub mod lending_pool;
 pub mod loan;
+pub mod mint_successor;
+pub mod pool_snapshots;
+pub mod price_feed;
+pub mod proof_receipt;
+pub mod proof_replay_cache;
+pub mod tier_table;
 pub mod viewing_key;
 
+pub use admin_multisig::*;
+pub use alt_repay_token::*;
+pub use blocklist::*;
+pub use collateral_whitelist::*;
 pub use credit_record::*;
+pub use global_config::*;
+pub use lender_position::*;
 pub use lending_pool::*;
 pub use loan::*;
+pub use mint_successor::*;
+pub use pool_snapshots::*;
+pub use price_feed::*;
+pub use proof_receipt::*;
+pub use proof_replay_cache::*;
+pub use tier_table::*;
 pub use viewing_key::*;
\ No newline at end of file
@@ -0,0 +1,47 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! POOL SNAPSHOTS - Capped ring of periodic pool metrics for historical charts
+//! ═══════════════════════════════════════════════════════════════════════════
+
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct PoolSnapshot {
+    pub timestamp: i64,
+    pub utilization: u16,     // bps
+    pub total_borrowed: u64,
+    pub rate: u16,            // Borrow rate in bps at snapshot time
+}
+
+impl PoolSnapshot {
+    pub const LEN: usize = 8 + 2 + 8 + 2;
+}
+
+#[account]
+#[derive(Default)]
+pub struct PoolSnapshots {
+    pub pool: Pubkey,
+    pub entries: [PoolSnapshot; 24],
+    pub head: u8,             // Index the next snapshot is written to
+    pub count: u8,
+    pub last_snapshot_at: i64,
+    pub bump: u8,
+}
+
+impl PoolSnapshots {
+    pub const LEN: usize = 8 + 32 + PoolSnapshot::LEN * 24 + 1 + 1 + 8 + 1;
+    pub const CAPACITY: usize = 24;
+    pub const MIN_SNAPSHOT_INTERVAL: i64 = 60 * 60;
+    pub const SEED_PREFIX: &'static [u8] = b"pool_snapshots";
+
+    pub fn can_snapshot(&self, current_time: i64) -> bool {
+        self.count == 0 || current_time >= self.last_snapshot_at.saturating_add(Self::MIN_SNAPSHOT_INTERVAL)
+    }
+
+    /// Overwrites the oldest entry once the ring is full
+    pub fn push(&mut self, snapshot: PoolSnapshot) {
+        self.entries[self.head as usize] = snapshot;
+        self.head = ((self.head as usize + 1) % Self::CAPACITY) as u8;
+        self.count = (self.count as usize + 1).min(Self::CAPACITY) as u8;
+        self.last_snapshot_at = snapshot.timestamp;
+    }
+}
@@ -0,0 +1,39 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! PRICE FEED - Pushed collateral price for a pool
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! The updater pushes the price of one whole collateral token in loan tokens.
+//! Pools without a feed keep valuing collateral 1:1 per whole token.
+
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct PriceFeed {
+    pub pool: Pubkey,
+    pub updater: Pubkey,
+    pub price: u64,                     // Loan tokens per whole collateral token, PRICE_SCALE = 1.0
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl PriceFeed {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 1;
+    pub const SEED_PREFIX: &'static [u8] = b"price_feed";
+    pub const PRICE_SCALE: u64 = 1_000_000;
+
+    pub fn is_stale(&self, current_time: i64, max_staleness: i64) -> bool {
+        current_time.saturating_sub(self.updated_at) > max_staleness
+    }
+
+    /// Value of an amount already rescaled to loan-mint decimals
+    pub fn value_of(&self, amount: u64) -> u64 {
+        (amount as u128 * self.price as u128 / Self::PRICE_SCALE as u128).min(u64::MAX as u128) as u64
+    }
+
+    /// Inverse of `value_of`: loan-decimal amount of collateral worth `value`
+    pub fn amount_for(&self, value: u64) -> u64 {
+        if self.price == 0 { return 0; }
+        (value as u128 * Self::PRICE_SCALE as u128 / self.price as u128).min(u64::MAX as u128) as u64
+    }
+}
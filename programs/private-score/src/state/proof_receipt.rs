@@ -0,0 +1,30 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! PROOF RECEIPT - Short-lived record of a verified proof, redeemed by a borrow
+//! ═══════════════════════════════════════════════════════════════════════════
+
+use anchor_lang::prelude::*;
+use crate::state::CreditTier;
+
+#[account]
+#[derive(Default)]
+pub struct ProofReceipt {
+    pub credit_record: Pubkey,
+    pub pool: Pubkey,
+    pub commitment: [u8; 32],       // Commitment the proof was verified against
+    pub nonce: u64,                 // Credit record nonce at verification
+    pub proof_hash: [u8; 32],
+    pub tier: CreditTier,           // Resolved at verification, under the pool's tier table if any
+    pub created_at: i64,
+    pub expires_at: i64,
+    pub bump: u8,
+}
+
+impl ProofReceipt {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 32 + 1 + 8 + 8 + 1;
+    pub const TTL: i64 = 5 * 60;
+    pub const SEED_PREFIX: &'static [u8] = b"proof_receipt";
+
+    pub fn is_expired(&self, current_time: i64) -> bool {
+        current_time > self.expires_at
+    }
+}
@@ -0,0 +1,38 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! PROOF REPLAY CACHE - Recently accepted proof hashes per pool
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! A ring buffer: once full, the oldest hash is overwritten. Nonces remain the
+//! primary replay defence; this catches byte-identical proofs on top of them.
+
+use anchor_lang::prelude::*;
+use crate::errors::PrivateScoreError;
+
+#[account]
+#[derive(Default)]
+pub struct ProofReplayCache {
+    pub pool: Pubkey,
+    pub hashes: [[u8; 32]; 32],
+    pub next_index: u8,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl ProofReplayCache {
+    pub const LEN: usize = 8 + 32 + 32 * 32 + 1 + 8 + 1;
+    pub const CAPACITY: usize = 32;
+    pub const SEED_PREFIX: &'static [u8] = b"proof_replay_cache";
+
+    pub fn contains(&self, proof_hash: &[u8; 32]) -> bool {
+        *proof_hash != [0u8; 32] && self.hashes.contains(proof_hash)
+    }
+
+    /// Remember `proof_hash`, rejecting it if it was seen recently
+    pub fn record(&mut self, proof_hash: [u8; 32], current_time: i64) -> Result<()> {
+        require!(!self.contains(&proof_hash), PrivateScoreError::ProofVerificationFailed);
+        self.hashes[self.next_index as usize] = proof_hash;
+        self.next_index = ((self.next_index as usize + 1) % Self::CAPACITY) as u8;
+        self.updated_at = current_time;
+        Ok(())
+    }
+}
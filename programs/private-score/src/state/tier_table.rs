@@ -0,0 +1,98 @@
+//! ═══════════════════════════════════════════════════════════════════════════
+//! TIER TABLE - Pool-defined score bands replacing the default FICO-like tiers
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//! Band `i` maps to `CreditTier::from_u8(i + 1)`, so the pool's per-tier
+//! parameters (max borrow, discounts) apply to custom bands unchanged.
+//! Pools without a table keep using the tier stored on the credit record.
+
+use anchor_lang::prelude::*;
+use crate::state::CreditTier;
+use crate::errors::PrivateScoreError;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct TierBand {
+    pub min_score: u16,     // Lowest proven score that falls in this band
+    pub qualifies: bool,    // Band may take credit-verified loans
+}
+
+impl TierBand {
+    pub const LEN: usize = 2 + 1;
+}
+
+#[account]
+#[derive(Default)]
+pub struct TierTable {
+    pub pool: Pubkey,
+    pub bands: [TierBand; 5],
+    pub num_bands: u8,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl TierTable {
+    pub const LEN: usize = 8 + 32 + TierBand::LEN * 5 + 1 + 8 + 1;
+    pub const MAX_BANDS: usize = 5;
+    pub const SEED_PREFIX: &'static [u8] = b"tier_table";
+
+    /// Bands must be non-empty and strictly ascending by `min_score`
+    pub fn set_bands(&mut self, bands: &[TierBand]) -> Result<()> {
+        require!(!bands.is_empty() && bands.len() <= Self::MAX_BANDS, PrivateScoreError::InvalidAccountState);
+        require!(
+            bands.windows(2).all(|pair| pair[0].min_score < pair[1].min_score),
+            PrivateScoreError::InvalidCreditScore
+        );
+        self.bands = [TierBand::default(); Self::MAX_BANDS];
+        self.bands[..bands.len()].copy_from_slice(bands);
+        self.num_bands = bands.len() as u8;
+        Ok(())
+    }
+
+    /// Highest band the score reaches; Unknown below the first band
+    pub fn tier_for_score(&self, score: u64) -> CreditTier {
+        self.bands[..self.num_bands as usize]
+            .iter()
+            .rposition(|band| score >= band.min_score as u64)
+            .map_or(CreditTier::Unknown, |index| CreditTier::from_u8(index as u8 + 1))
+    }
+
+    pub fn qualifies(&self, tier: CreditTier) -> bool {
+        let index = tier.to_u8() as usize;
+        index > 0 && index <= self.num_bands as usize && self.bands[index - 1].qualifies
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> TierTable {
+        let mut table = TierTable::default();
+        table
+            .set_bands(&[
+                TierBand { min_score: 600, qualifies: false },
+                TierBand { min_score: 700, qualifies: true },
+            ])
+            .unwrap();
+        table
+    }
+
+    #[test]
+    fn tier_for_score_picks_the_highest_band_reached() {
+        let table = table();
+        assert!(table.tier_for_score(599) == CreditTier::Unknown);
+        assert!(table.tier_for_score(600) == CreditTier::Poor);
+        assert!(table.tier_for_score(850) == CreditTier::Fair);
+        assert!(!table.qualifies(CreditTier::Poor));
+        assert!(table.qualifies(CreditTier::Fair));
+        assert!(!table.qualifies(CreditTier::Good));
+    }
+
+    #[test]
+    fn set_bands_rejects_unordered_bands() {
+        let mut table = TierTable::default();
+        let bands = [TierBand { min_score: 700, qualifies: true }, TierBand { min_score: 700, qualifies: true }];
+        assert!(table.set_bands(&bands).is_err());
+        assert!(table.set_bands(&[]).is_err());
+    }
+}
@@ -4,6 +4,7 @@
 
 use anchor_lang::prelude::*;
 use crate::errors::PrivateScoreError;
+use crate::state::CreditTier;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
 pub enum AccessLevel {
@@ -13,6 +14,7 @@ pub enum AccessLevel {
     BasicHistory,       // Level 2: Tier + loan history summary
     FullAccess,         // Level 3: Full credit record
     RegulatoryAccess,   // Level 4: Everything including PII
+    PredicateOnly,      // Level 5: Only whether a proof meets the viewer's threshold
 }
 
 impl AccessLevel {
@@ -22,6 +24,7 @@ impl AccessLevel {
             2 => AccessLevel::BasicHistory,
             3 => AccessLevel::FullAccess,
             4 => AccessLevel::RegulatoryAccess,
+            5 => AccessLevel::PredicateOnly,
             _ => AccessLevel::None,
         }
     }
@@ -33,11 +36,16 @@ impl AccessLevel {
             AccessLevel::BasicHistory => 2,
             AccessLevel::FullAccess => 3,
             AccessLevel::RegulatoryAccess => 4,
+            AccessLevel::PredicateOnly => 5,
         }
     }
 
     pub fn can_view_tier(&self) -> bool {
-        !matches!(self, AccessLevel::None)
+        !matches!(self, AccessLevel::None | AccessLevel::PredicateOnly)
+    }
+
+    pub fn is_predicate_only(&self) -> bool {
+        matches!(self, AccessLevel::PredicateOnly)
     }
 
     pub fn can_view_history(&self) -> bool {
@@ -67,8 +75,11 @@ pub struct ViewingKey {
     pub access_level: AccessLevel,
     pub status: ViewingKeyStatus,
     pub granted_at: i64,
+    pub active_from: i64,               // Disclosures before this are rejected (e.g. audit period start)
     pub expires_at: i64,
+    pub expiry_warning_window: i64,     // Disclosures this close to expiry emit AccessExpiringSoon (0 = off)
     pub last_accessed_at: i64,
+    pub min_access_interval: i64,
     pub access_count: u32,
     pub max_accesses: u32,
     pub purpose: [u8; 32],
@@ -77,21 +88,28 @@ pub struct ViewingKey {
     pub one_time_use: bool,
     pub notify_on_access: bool,
     pub access_restriction: [u8; 32],
+    pub proof_pool: Pubkey,             // Regulatory disclosures must carry a proof verified for this pool (Default = none)
     pub _reserved: [u8; 32],
     pub bump: u8,
 }
 
 impl ViewingKey {
-    pub const LEN: usize = 8 + 32 + 32 + 32 + 1 + 1 + 8 + 8 + 8 + 4 + 4 + 32 + 64 + 12 + 1 + 1 + 32 + 32 + 1;
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 1 + 1 + 8 + 8 + 8 + 8 + 8 + 8 + 4 + 4 + 32 + 64 + 12 + 1 + 1 + 32 + 32 + 32 + 1;
     pub const DEFAULT_EXPIRY: i64 = 7 * 24 * 60 * 60;
     pub const MAX_EXPIRY: i64 = 365 * 24 * 60 * 60;
+    pub const DEFAULT_EXPIRY_WARNING_WINDOW: i64 = 3 * 24 * 60 * 60;
 
     pub fn is_valid(&self, current_time: i64) -> bool {
         self.status == ViewingKeyStatus::Active
+            && self.is_active_at(current_time)
             && !self.is_expired(current_time)
             && !self.is_access_exhausted()
     }
 
+    pub fn is_active_at(&self, current_time: i64) -> bool {
+        current_time >= self.active_from
+    }
+
     pub fn is_expired(&self, current_time: i64) -> bool {
         self.expires_at > 0 && current_time > self.expires_at
     }
@@ -101,7 +119,13 @@ impl ViewingKey {
     }
 
     pub fn record_access(&mut self, current_time: i64) -> Result<()> {
+        require!(self.is_active_at(current_time), PrivateScoreError::ViewingKeyNotActive);
         require!(self.is_valid(current_time), PrivateScoreError::InvalidViewingKey);
+        require!(
+            self.last_accessed_at == 0
+                || current_time >= self.last_accessed_at.saturating_add(self.min_access_interval),
+            PrivateScoreError::DisclosureDenied
+        );
         self.access_count = self.access_count.saturating_add(1);
         self.last_accessed_at = current_time;
 
@@ -111,6 +135,10 @@ impl ViewingKey {
         Ok(())
     }
 
+    pub fn requires_proof(&self) -> bool {
+        self.proof_pool != Pubkey::default()
+    }
+
     pub fn revoke(&mut self) {
         self.status = ViewingKeyStatus::Revoked;
     }
@@ -120,6 +148,14 @@ impl ViewingKey {
         else { Some(self.max_accesses.saturating_sub(self.access_count)) }
     }
 
+    /// Still valid, but inside the owner's pre-expiry warning window
+    pub fn is_expiring_soon(&self, current_time: i64) -> bool {
+        self.expiry_warning_window > 0
+            && self.expires_at > 0
+            && current_time <= self.expires_at
+            && current_time >= self.expires_at.saturating_sub(self.expiry_warning_window)
+    }
+
     pub fn time_remaining(&self, current_time: i64) -> i64 {
         self.expires_at.saturating_sub(current_time).max(0)
     }
@@ -139,10 +175,60 @@ pub struct DisclosureRequest {
     pub requested_at: i64,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[event]
 pub struct DisclosureResponse {
+    pub credit_record: Pubkey,
+    pub viewer: Pubkey,
     pub disclosed_at: i64,
+    pub tier: CreditTier,
+    pub loans_taken: u32,
+    pub on_time_repayments: u32,
+    pub late_repayments: u32,
     pub tier_disclosed: bool,
     pub history_disclosed: bool,
     pub full_access_granted: bool,
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct PredicateDisclosure {
+    pub credit_record: Pubkey,
+    pub viewer: Pubkey,
+    pub disclosed_at: i64,
+    pub required_score: u16,
+    pub meets_requirement: bool,
+}
+
+#[event]
+pub struct AccessExpiringSoon {
+    pub credit_record: Pubkey,
+    pub owner: Pubkey,
+    pub viewer: Pubkey,
+    pub expires_at: i64,
+    pub time_remaining: i64,
+    pub timestamp: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_access_enforces_the_minimum_interval() {
+        let mut key = ViewingKey { min_access_interval: 60, ..Default::default() };
+        key.record_access(100).unwrap();
+        assert!(key.record_access(159).is_err());
+        key.record_access(160).unwrap();
+        assert_eq!(key.access_count, 2);
+        assert_eq!(key.last_accessed_at, 160);
+    }
+
+    #[test]
+    fn record_access_waits_for_active_from() {
+        let mut key = ViewingKey { active_from: 1_000, ..Default::default() };
+        assert!(!key.is_valid(999));
+        assert!(key.record_access(999).is_err());
+        assert_eq!(key.access_count, 0);
+        key.record_access(1_000).unwrap();
+        assert_eq!(key.access_count, 1);
+    }
+}